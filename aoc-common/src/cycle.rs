@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// The shape of a sequence start, next(start), next(next(start)), ... of a function on finitely
+// many states. After prefix steps it enters a cycle and repeats itself every period steps
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub period: usize
+}
+
+impl Cycle {
+    // the step within the prefix or the first round of the cycle with the same state as step n
+    pub fn index(&self, n: usize) -> usize {
+        if n < self.prefix {
+            return n;
+        }
+
+        self.prefix + (n - self.prefix) % self.period
+    }
+}
+
+// remembers every state it sees, so it needs only prefix + period steps. The states up to the
+// second round of the cycle are returned with it, states[cycle.index(n)] is the state at step n
+pub fn find<S, F>(start: S, mut next: F) -> (Cycle, Vec<S>) where S: Clone + Hash + Eq, F: FnMut(&S) -> S {
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut states: Vec<S> = Vec::new();
+    let mut state = start;
+    loop {
+        if let Some(&prefix) = seen.get(&state) {
+            let cycle = Cycle { prefix, period: states.len() - prefix };
+
+            return (cycle, states);
+        }
+
+        let following = next(&state);
+        seen.insert(state.clone(), states.len());
+        states.push(state);
+        state = following;
+    }
+}
+
+// the state after n steps, without taking more steps than it needs to see the cycle
+pub fn nth<S, F>(start: S, mut next: F, n: usize) -> S where S: Clone + Hash + Eq, F: FnMut(&S) -> S {
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut states: Vec<S> = Vec::new();
+    let mut state = start;
+    while states.len() < n {
+        if let Some(&prefix) = seen.get(&state) {
+            let cycle = Cycle { prefix, period: states.len() - prefix };
+
+            return states.swap_remove(cycle.index(n));
+        }
+
+        let following = next(&state);
+        seen.insert(state.clone(), states.len());
+        states.push(state);
+        state = following;
+    }
+
+    state
+}
+
+// Brent's algorithm keeps only two states, for states which are too large or too many to store.
+// The hare runs ahead in rounds of doubling length until it meets the tortoise, which gives the
+// period. Then both start over, period steps apart, and meet at the start of the cycle
+pub fn brent<S, F>(start: S, mut next: F) -> Cycle where S: Clone + Eq, F: FnMut(&S) -> S {
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = start.clone();
+    let mut hare = next(&start);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = next(&hare);
+        period += 1;
+    }
+
+    let mut tortoise = start.clone();
+    let mut hare = start;
+    for _ in 0..period {
+        hare = next(&hare);
+    }
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        prefix += 1;
+    }
+
+    Cycle { prefix, period }
+}
+
+// Floyd's algorithm, the hare takes two steps for every step of the tortoise. It needs more
+// calls of next than Brent's, but is the one to compare against
+pub fn floyd<S, F>(start: S, mut next: F) -> Cycle where S: Clone + Eq, F: FnMut(&S) -> S {
+    let mut tortoise = next(&start);
+    let mut hare = next(&tortoise);
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        hare = next(&hare);
+    }
+
+    // the tortoise is a multiple of the period away from the start, so one step at a time
+    // from the start and from the meeting point both arrive at the start of the cycle
+    let mut prefix = 0;
+    let mut tortoise = start;
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        prefix += 1;
+    }
+
+    let mut period = 1;
+    hare = next(&tortoise);
+    while tortoise != hare {
+        hare = next(&hare);
+        period += 1;
+    }
+
+    Cycle { prefix, period }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::{brent, find, floyd, nth, Cycle};
+
+    // a random function on a few numbers, every start runs into a cycle sooner or later
+    fn function() -> impl Strategy<Value = (Vec<usize>, usize)> {
+        (1..40usize).prop_flat_map(|size| (prop::collection::vec(0..size, size), 0..size))
+    }
+
+    // walks until a state comes up the second time
+    fn brute_force(function: &[usize], start: usize) -> Cycle {
+        let mut states = vec![start];
+        loop {
+            let state = function[*states.last().unwrap()];
+            if let Some(prefix) = states.iter().position(|&s| s == state) {
+                return Cycle { prefix, period: states.len() - prefix };
+            }
+            states.push(state);
+        }
+    }
+
+    #[test]
+    fn test_index() {
+        let cycle = Cycle { prefix: 3, period: 4 };
+
+        assert_eq!((0..12).map(|n| cycle.index(n)).collect::<Vec<usize>>(), vec![0, 1, 2, 3, 4, 5, 6, 3, 4, 5, 6, 3]);
+    }
+
+    proptest! {
+        #[test]
+        fn test_cycles((function, start) in function(), n in 0..200usize) {
+            let expected = brute_force(&function, start);
+            let next = |&s: &usize| function[s];
+
+            let (cycle, states) = find(start, next);
+            prop_assert_eq!(cycle, expected);
+            prop_assert_eq!(states.len(), cycle.prefix + cycle.period);
+            prop_assert_eq!(brent(start, next), expected);
+            prop_assert_eq!(floyd(start, next), expected);
+
+            let walked = (0..n).fold(start, |s, _| function[s]);
+            prop_assert_eq!(nth(start, next, n), walked);
+            prop_assert_eq!(states[cycle.index(n)], walked);
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use std::fmt;
+use std::io;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::process;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    Usage(String),
+    Io { path: PathBuf, source: io::Error },
+    // talking to the puzzle server went wrong
+    Http { url: String, message: String },
+    Parse(ParseError)
+}
+
+// line and column are counted from 1, like every editor does
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize
+}
+
+#[derive(Debug)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    pub position: Option<Position>,
+    pub token: String,
+    pub message: String,
+    // the memory range of the offending token. Parsers only hand out slices of the
+    // input, so this is enough to find the position once the whole input is known
+    span: Range<usize>
+}
+
+impl Error {
+    // a parse error for the offending token, which has to be a slice of the input.
+    // The position is filled in by locate once the error reaches the input it came from
+    pub fn parse(token: &str, message: impl Into<String>) -> Self {
+        let start = token.as_ptr() as usize;
+
+        Error::Parse(ParseError {
+            file: None,
+            position: None,
+            token: token.to_string(),
+            message: message.into(),
+            span: start..start + token.len()
+        })
+    }
+
+    // resolve line and column of a parse error against the input it was raised for
+    pub fn locate(self, input: &str) -> Self {
+        match self {
+            Error::Parse(mut e) if e.position.is_none() => {
+                let start = input.as_ptr() as usize;
+                if e.span.start >= start && e.span.end <= start + input.len() {
+                    e.position = Some(Position::of(input, e.span.start - start));
+                }
+
+                Error::Parse(e)
+            },
+            e => e
+        }
+    }
+
+    // remember which file a parse error came from
+    pub fn in_file(self, path: &Path) -> Self {
+        match self {
+            Error::Parse(mut e) => {
+                e.file = Some(path.to_path_buf());
+
+                Error::Parse(e)
+            },
+            e => e
+        }
+    }
+
+    // print the error and terminate with a non-zero exit code
+    pub fn exit(&self) -> ! {
+        eprintln!("error: {}", self);
+
+        process::exit(1)
+    }
+}
+
+impl Position {
+    // position of the byte offset within the text
+    fn of(text: &str, offset: usize) -> Self {
+        let before = &text[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let column = before[line_start..].chars().count() + 1;
+
+        Position { line, column }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Usage(usage) => write!(f, "{}", usage),
+            Error::Io { path, source } => write!(f, "could not read {}: {}", path.display(), source),
+            Error::Http { url, message } => write!(f, "request to {} failed: {}", url, message),
+            Error::Parse(e) => write!(f, "{}", e)
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+        if let Some(position) = &self.position {
+            write!(f, "{}:{}:", position.line, position.column)?;
+        }
+        if self.file.is_some() || self.position.is_some() {
+            write!(f, " ")?;
+        }
+
+        if self.token.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{} at '{}'", self.message, self.token)
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{Error, Position};
+
+    #[test]
+    fn test_locate() {
+        let input = "32T3K 765\nT55J5 684\nKK6X7 28";
+        let token = &input.lines().nth(2).unwrap()[3..4];
+
+        let e = Error::parse(token, "unknown card").locate(input).in_file(Path::new("day-07.txt"));
+        let Error::Parse(parse_error) = &e else { panic!("Expected a parse error") };
+
+        assert_eq!(parse_error.position, Some(Position { line: 3, column: 4 }));
+        assert_eq!(e.to_string(), "day-07.txt:3:4: unknown card at 'X'");
+    }
+
+    #[test]
+    fn test_locate_foreign_token() {
+        let input = "KK6X7 28";
+        let other = String::from("X");
+
+        let e = Error::parse(&other, "unknown card").locate(input);
+
+        assert_eq!(e.to_string(), "unknown card at 'X'");
+    }
+}
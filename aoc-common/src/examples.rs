@@ -0,0 +1,123 @@
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, Result};
+use crate::input;
+use crate::solution::{Part, Puzzle};
+
+// every day keeps the examples of its puzzle text in fixtures/, next to a manifest
+// with the answers they should give
+pub const FIXTURES: &str = "fixtures";
+pub const MANIFEST: &str = "answers.txt";
+
+// one line of the manifest: "<file> <part> <answer>", e.g. "example.txt 2 5905"
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Example {
+    pub file: String,
+    pub part: Part,
+    pub answer: String
+}
+
+impl Example {
+    fn from(line: &str) -> Result<Self> {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        let [file, part, answer] = tokens[..] else {
+            return Err(Error::parse(line, "expected '<file> <part> <answer>'"));
+        };
+
+        let part = Part::from_number(input::number::<u8>(part)?).ok_or_else(||
+            Error::parse(part, "expected part 1 or 2")
+        )?;
+
+        Ok(Example { file: file.to_string(), part, answer: answer.to_string() })
+    }
+}
+
+// the examples listed in the manifest, empty lines and lines starting with # are skipped
+pub fn parse_manifest(manifest: &str) -> Result<Vec<Example>> {
+    input::lines(manifest).iter()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(Example::from)
+        .collect()
+}
+
+pub fn read_manifest(fixtures: &Path) -> Result<Vec<Example>> {
+    let path = fixtures.join(MANIFEST);
+    let manifest = input::read_input(&path)?;
+
+    parse_manifest(&manifest).map_err(|e| e.locate(&manifest).in_file(&path))
+}
+
+// what went wrong for a single example
+#[derive(Debug)]
+pub struct Failure {
+    pub example: Example,
+    pub actual: std::result::Result<String, String>
+}
+
+impl Failure {
+    fn describe(&self, day: u8) -> String {
+        let what = format!("day {} part {} ({})", day, self.example.part, self.example.file);
+
+        match &self.actual {
+            Ok(answer) => format!("{}: expected {}, got {}", what, self.example.answer, answer),
+            Err(e) => format!("{}: expected {}, failed with {}", what, self.example.answer, e)
+        }
+    }
+}
+
+// run the puzzle on all examples of its crate and collect the ones with a wrong answer
+pub fn verify(puzzle: &dyn Puzzle, crate_dir: &Path) -> Result<Vec<Failure>> {
+    let fixtures: PathBuf = crate_dir.join(FIXTURES);
+    let mut failures: Vec<Failure> = Vec::new();
+
+    for example in read_manifest(&fixtures)? {
+        let path = fixtures.join(&example.file);
+        let actual = input::read_input(&path)
+            .and_then(|input| puzzle.parse_input(&input).map_err(|e| e.in_file(&path)))
+            .map(|parsed| puzzle.solve(parsed.as_ref(), example.part).to_string())
+            .map_err(|e| e.to_string());
+
+        if actual.as_ref() != Ok(&example.answer) {
+            failures.push(Failure { example, actual });
+        }
+    }
+
+    Ok(failures)
+}
+
+// the test every day runs: panics with one line per wrong answer
+pub fn check(puzzle: &dyn Puzzle, crate_dir: &str) {
+    let failures = verify(puzzle, Path::new(crate_dir)).unwrap_or_else(|e|
+        panic!("day {}: {}", puzzle.day(), e)
+    );
+
+    if !failures.is_empty() {
+        let report: Vec<String> = failures.iter().map(|f| f.describe(puzzle.day())).collect();
+        panic!("{} example(s) failed:\n{}", failures.len(), report.join("\n"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_manifest, Example};
+    use crate::Part;
+
+    #[test]
+    fn test_parse_manifest() {
+        let manifest = "# file part answer\nexample.txt 1 142\n\nother.txt 2 abc\n";
+
+        assert_eq!(parse_manifest(manifest).unwrap(), vec![
+            Example { file: String::from("example.txt"), part: Part::One, answer: String::from("142") },
+            Example { file: String::from("other.txt"), part: Part::Two, answer: String::from("abc") }
+        ]);
+    }
+
+    #[test]
+    fn test_parse_manifest_error() {
+        let manifest = "example.txt 1 142\nexample.txt 3 7\n";
+        let e = parse_manifest(manifest).unwrap_err().locate(manifest);
+
+        assert_eq!(e.to_string(), "2:13: expected part 1 or 2 at '3'");
+    }
+}
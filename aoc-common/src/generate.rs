@@ -0,0 +1,128 @@
+use crate::error::Result;
+use crate::random::Rng;
+use crate::solution::{Answer, Part, Puzzle, Solution};
+
+// A day which can make up its own inputs. Next to the generator it has a slow but
+// simple reference solution, which the real solution is checked against
+pub trait Generator: Solution {
+    // a valid random input, size scales how big it gets
+    fn generate(rng: &mut Rng, size: usize) -> String;
+
+    // the answer the part should have, or Unsolved if there is no reference for it
+    fn reference(input: &str, part: Part) -> Answer;
+}
+
+// Object safe view on a Generator, like Puzzle is for Solution
+pub trait Stress: Puzzle {
+    fn generate(&self, seed: u64, size: usize) -> String;
+
+    fn reference(&self, input: &str, part: Part) -> Answer;
+}
+
+impl<G> Stress for G where G: Generator + Sync, G::Input: 'static {
+    fn generate(&self, seed: u64, size: usize) -> String {
+        G::generate(&mut Rng::new(seed), size)
+    }
+
+    fn reference(&self, input: &str, part: Part) -> Answer {
+        G::reference(input, part)
+    }
+}
+
+// a generated input on which the solution disagrees with the reference
+#[derive(Debug)]
+pub struct Mismatch {
+    pub day: u8,
+    pub seed: u64,
+    pub part: Part,
+    pub expected: Answer,
+    pub actual: Answer
+}
+
+// solve a generated input with both the solution and the reference. Parts without a
+// reference are left out, a solution which gives up where the reference has an answer
+// is a mismatch like any other
+pub fn compare(puzzle: &dyn Stress, seed: u64, size: usize) -> Result<Vec<Mismatch>> {
+    let input = puzzle.generate(seed, size);
+    let parsed = puzzle.parse_input(&input)?;
+
+    let mut mismatches: Vec<Mismatch> = Vec::new();
+    for part in Part::BOTH {
+        let actual = puzzle.solve(parsed.as_ref(), part);
+
+        let expected = puzzle.reference(&input, part);
+        if expected != Answer::Unsolved && expected != actual {
+            mismatches.push(Mismatch { day: puzzle.day(), seed, part, expected, actual });
+        }
+    }
+
+    Ok(mismatches)
+}
+
+// the test every generating day runs: a few small inputs, panics on any difference
+pub fn check(puzzle: &dyn Stress, seeds: u64, size: usize) {
+    let mut report: Vec<String> = Vec::new();
+
+    for seed in 0..seeds {
+        match compare(puzzle, seed, size) {
+            Ok(mismatches) => report.extend(mismatches.iter().map(|m|
+                format!("day {} part {} seed {}: expected {}, got {}", m.day, m.part, m.seed, m.expected, m.actual)
+            )),
+            Err(e) => report.push(format!("day {} seed {}: generated input does not parse: {}", puzzle.day(), seed, e))
+        }
+    }
+
+    if !report.is_empty() {
+        panic!("{} generated input(s) failed (size {}):\n{}", report.len(), size, report.join("\n"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compare, Generator};
+    use crate::random::Rng;
+    use crate::{Answer, Part, Result, Solution};
+
+    // counts the lines of the input in part 1 and never solves part 2
+    struct Lines;
+
+    impl Solution for Lines {
+        const DAY: u8 = 0;
+        const TITLE: &'static str = "Lines";
+
+        type Input = usize;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(input.lines().count())
+        }
+
+        fn part1(lines: &Self::Input) -> Answer {
+            (*lines).into()
+        }
+
+        fn part2(_lines: &Self::Input) -> Answer {
+            Answer::Unsolved
+        }
+    }
+
+    impl Generator for Lines {
+        fn generate(_rng: &mut Rng, size: usize) -> String {
+            "line\n".repeat(size)
+        }
+
+        fn reference(input: &str, part: Part) -> Answer {
+            match part {
+                Part::One => input.lines().count().into(),
+                Part::Two => Answer::from(42)
+            }
+        }
+    }
+
+    #[test]
+    fn test_unsolved_part() {
+        let mismatches = compare(&Lines, 0, 3).unwrap();
+
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!((mismatches[0].part, &mismatches[0].expected, &mismatches[0].actual), (Part::Two, &Answer::from(42), &Answer::Unsolved));
+    }
+}
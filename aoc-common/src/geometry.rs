@@ -0,0 +1,295 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::error::{Error, Result};
+
+// A point (or vector) in the plane. Like in the puzzle maps, y grows downwards
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+}
+
+impl<T> Point<T> where T: Copy + PartialOrd + Sub<Output = T> + Add<Output = T> {
+    // works for unsigned coordinates as well, we never subtract the larger value
+    pub fn manhattan(&self, other: &Point<T>) -> T {
+        let dx = if self.x > other.x { self.x - other.x } else { other.x - self.x };
+        let dy = if self.y > other.y { self.y - other.y } else { other.y - self.y };
+
+        dx + dy
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point { x, y }
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(p: Point<T>) -> Self {
+        (p.x, p.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, other: Point<T>) -> Self::Output {
+        Point { x: self.x + other.x, y: self.y + other.y }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, other: Point<T>) -> Self::Output {
+        Point { x: self.x - other.x, y: self.y - other.y }
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Point<T>;
+
+    fn mul(self, factor: T) -> Self::Output {
+        Point { x: self.x * factor, y: self.y * factor }
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Point<T>;
+
+    fn neg(self) -> Self::Output {
+        Point { x: -self.x, y: -self.y }
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Point<T>) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Point<T>) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left
+}
+
+impl Direction {
+    // clockwise, starting at the top
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    pub fn turn_left(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down
+        }
+    }
+
+    pub fn turn_right(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up
+        }
+    }
+
+    pub fn turn(&self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right()
+        }
+    }
+
+    pub fn reverse(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right
+        }
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        *self == Direction::Left || *self == Direction::Right
+    }
+
+    // one step into this direction
+    pub fn vector<T: From<i8>>(&self) -> Point<T> {
+        let (x, y): (i8, i8) = match self {
+            Direction::Up => (0, -1),
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0)
+        };
+
+        Point { x: T::from(x), y: T::from(y) }
+    }
+
+    // the direction of a single step, None for anything else
+    pub fn from_vector<T: From<i8> + PartialEq>(vector: Point<T>) -> Option<Self> {
+        Direction::ALL.into_iter().find(|d| d.vector::<T>() == vector)
+    }
+
+    // U, D, L or R, like in a dig plan
+    pub fn from_letter(token: &str) -> Result<Self> {
+        match token {
+            "U" => Ok(Direction::Up),
+            "R" => Ok(Direction::Right),
+            "D" => Ok(Direction::Down),
+            "L" => Ok(Direction::Left),
+            _ => Err(Error::parse(token, "expected one of U, R, D or L"))
+        }
+    }
+
+    pub fn letter(&self) -> char {
+        match self {
+            Direction::Up => 'U',
+            Direction::Right => 'R',
+            Direction::Down => 'D',
+            Direction::Left => 'L'
+        }
+    }
+
+    // the last digit of a hex code: 0 is right, then clockwise
+    pub fn from_hex_digit(token: &str) -> Result<Self> {
+        match token {
+            "0" => Ok(Direction::Right),
+            "1" => Ok(Direction::Down),
+            "2" => Ok(Direction::Left),
+            "3" => Ok(Direction::Up),
+            _ => Err(Error::parse(token, "expected a direction digit from 0 to 3"))
+        }
+    }
+
+    pub fn hex_digit(&self) -> char {
+        match self {
+            Direction::Right => '0',
+            Direction::Down => '1',
+            Direction::Left => '2',
+            Direction::Up => '3'
+        }
+    }
+
+    // ^, >, v or <, like the slopes on a map
+    pub fn from_arrow(token: &str) -> Result<Self> {
+        match token {
+            "^" => Ok(Direction::Up),
+            ">" => Ok(Direction::Right),
+            "v" => Ok(Direction::Down),
+            "<" => Ok(Direction::Left),
+            _ => Err(Error::parse(token, "expected one of ^, >, v or <"))
+        }
+    }
+
+    pub fn arrow(&self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<'
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.arrow())
+    }
+}
+
+// a turn relative to the current direction, e.g. the L/R instructions of a map
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Turn {
+    Left,
+    Right
+}
+
+impl Turn {
+    pub fn from_letter(token: &str) -> Result<Self> {
+        match token {
+            "L" => Ok(Turn::Left),
+            "R" => Ok(Turn::Right),
+            _ => Err(Error::parse(token, "expected L or R"))
+        }
+    }
+}
+
+impl fmt::Display for Turn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Turn::Left => write!(f, "L"),
+            Turn::Right => write!(f, "R")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, Point};
+
+    #[test]
+    fn test_point_arithmetic() {
+        let a = Point::new(3i64, -2);
+        let b = Point::new(-1i64, 5);
+
+        assert_eq!(a + b, Point::new(2, 3));
+        assert_eq!(a - b, Point::new(4, -7));
+        assert_eq!(a * 3, Point::new(9, -6));
+        assert_eq!(-a, Point::new(-3, 2));
+        assert_eq!(a.manhattan(&b), 11);
+        assert_eq!(Point::new(1u64, 9).manhattan(&Point::new(4, 2)), 10);
+    }
+
+    #[test]
+    fn test_turns() {
+        for d in Direction::ALL {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.turn_right().turn_right(), d.reverse());
+            assert_eq!(d.vector::<i32>() + d.reverse().vector(), Point::new(0, 0));
+            assert_eq!(Direction::from_vector(d.vector::<i64>()), Some(d));
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Direction::from_letter("L").unwrap(), Direction::Left);
+        assert_eq!(Direction::from_hex_digit("3").unwrap(), Direction::Up);
+        assert_eq!(Direction::from_arrow("v").unwrap(), Direction::Down);
+        assert!(Direction::from_letter("X").is_err());
+        assert!(Direction::from_hex_digit("4").is_err());
+
+        for d in Direction::ALL {
+            assert_eq!(Direction::from_letter(&d.letter().to_string()).unwrap(), d);
+            assert_eq!(Direction::from_hex_digit(&d.hex_digit().to_string()).unwrap(), d);
+            assert_eq!(Direction::from_arrow(&d.arrow().to_string()).unwrap(), d);
+        }
+    }
+}
@@ -0,0 +1,400 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+use crate::grid::{Grid, Position};
+
+// The searches take the neighbours of a node as a callback, so they run on a grid as well as on
+// a Graph, e.g. bfs(start, |p| grid.neighbours4(p).filter(|&n| grid[n] != '#'))
+
+// the number of steps to every node reachable from start
+pub fn bfs<N, F, I>(start: N, mut neighbours: F) -> HashMap<N, usize> where N: Copy + Eq + Hash, F: FnMut(N) -> I, I: IntoIterator<Item = N> {
+    let mut distances: HashMap<N, usize> = HashMap::from([(start, 0)]);
+    let mut queue: VecDeque<N> = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        let distance = distances[&node];
+        for next in neighbours(node) {
+            if let Entry::Vacant(entry) = distances.entry(next) {
+                entry.insert(distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    distances
+}
+
+// A path through a graph with the sum of the weights of its edges
+#[derive(Clone, Debug, PartialEq)]
+pub struct Path<N> {
+    pub length: u64,
+    // from the start to the end, both included
+    pub nodes: Vec<N>
+}
+
+// the shortest path to the first node which is a goal. The heuristic has to estimate the rest
+// of the way from a node without ever overestimating it, with |_| 0 this is Dijkstra
+pub fn astar<N, F, I, G, H>(start: N, is_goal: G, mut neighbours: F, heuristic: H) -> Option<Path<N>>
+where N: Copy + Eq + Hash, F: FnMut(N) -> I, I: IntoIterator<Item = (N, u64)>, G: Fn(N) -> bool, H: Fn(N) -> u64 {
+    let mut costs: HashMap<N, u64> = HashMap::from([(start, 0)]);
+    let mut previous: HashMap<N, N> = HashMap::new();
+    // the heap holds indices into nodes, so the nodes do not need to be ordered
+    let mut nodes: Vec<N> = vec![start];
+    let mut heap: BinaryHeap<Reverse<(u64, u64, usize)>> = BinaryHeap::from([Reverse((heuristic(start), 0, 0))]);
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        let node = nodes[index];
+        if cost > costs[&node] {
+            continue; // a shorter way to the node was found after this one was queued
+        }
+        if is_goal(node) {
+            let mut path = vec![node];
+            while let Some(&before) = previous.get(path.last().unwrap()) {
+                path.push(before);
+            }
+            path.reverse();
+
+            return Some(Path { length: cost, nodes: path });
+        }
+
+        for (next, weight) in neighbours(node) {
+            let next_cost = cost + weight;
+            if costs.get(&next).is_some_and(|&c| c <= next_cost) {
+                continue;
+            }
+
+            costs.insert(next, next_cost);
+            previous.insert(next, node);
+            nodes.push(next);
+            heap.push(Reverse((next_cost + heuristic(next), next_cost, nodes.len() - 1)));
+        }
+    }
+
+    None
+}
+
+pub fn dijkstra<N, F, I, G>(start: N, is_goal: G, neighbours: F) -> Option<Path<N>>
+where N: Copy + Eq + Hash, F: FnMut(N) -> I, I: IntoIterator<Item = (N, u64)>, G: Fn(N) -> bool {
+    astar(start, is_goal, neighbours, |_| 0)
+}
+
+// the groups of nodes which are connected to each other. The neighbours have to go both ways,
+// for a directed graph this gives what can be reached from the first node of every group
+pub fn components<N, F, I>(nodes: impl IntoIterator<Item = N>, mut neighbours: F) -> Vec<Vec<N>>
+where N: Copy + Eq + Hash, F: FnMut(N) -> I, I: IntoIterator<Item = N> {
+    let mut seen: HashSet<N> = HashSet::new();
+    let mut components: Vec<Vec<N>> = Vec::new();
+
+    for node in nodes {
+        if seen.contains(&node) {
+            continue;
+        }
+
+        let component: Vec<N> = bfs(node, &mut neighbours).into_keys().collect();
+        seen.extend(component.iter().copied());
+        components.push(component);
+    }
+
+    components
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Edge<N> {
+    pub to: N,
+    pub weight: u64,
+    // the nodes of a contracted corridor between the two ends of the edge, in walking order
+    pub via: Vec<N>
+}
+
+// Nodes with weighted, directed edges. An undirected graph has every edge in both directions
+#[derive(Clone, Debug)]
+pub struct Graph<N> {
+    edges: HashMap<N, Vec<Edge<N>>>
+}
+
+impl<N> Default for Graph<N> {
+    fn default() -> Self {
+        Graph { edges: HashMap::new() }
+    }
+}
+
+impl Graph<Position> {
+    // the tiles of a grid with an edge of weight 1 to every neighbour the callback gives.
+    // None leaves the tile out, like a wall
+    pub fn from_grid<T, F, I>(grid: &Grid<T>, neighbours: F) -> Self
+    where F: Fn(Position, &T) -> Option<I>, I: IntoIterator<Item = Position> {
+        let mut graph: Graph<Position> = Graph::new();
+        for (position, cell) in grid.iter() {
+            let Some(next) = neighbours(position, cell) else { continue };
+
+            graph.add_node(position);
+            for n in next {
+                graph.add_edge(position, n, 1);
+            }
+        }
+
+        graph
+    }
+}
+
+impl<N> Graph<N> where N: Copy + Eq + Hash {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_node(&mut self, node: N) {
+        self.edges.entry(node).or_default();
+    }
+
+    pub fn add_edge(&mut self, from: N, to: N, weight: u64) {
+        self.add_node(to);
+        self.edges.entry(from).or_default().push(Edge { to, weight, via: Vec::new() });
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = N> + '_ {
+        self.edges.keys().copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+
+    pub fn edges(&self, node: N) -> &[Edge<N>] {
+        self.edges.get(&node).map(|edges| &edges[..]).unwrap_or(&[])
+    }
+
+    pub fn neighbours(&self, node: N) -> impl Iterator<Item = N> + '_ {
+        self.edges(node).iter().map(|edge| edge.to)
+    }
+
+    pub fn bfs(&self, start: N) -> HashMap<N, usize> {
+        bfs(start, |node| self.neighbours(node))
+    }
+
+    // the shortest path with the corridors of contracted edges filled in
+    pub fn shortest_path(&self, start: N, end: N) -> Option<Path<N>> {
+        let path = dijkstra(start, |node| node == end, |node|
+            self.edges(node).iter().map(|edge| (edge.to, edge.weight))
+        )?;
+
+        let mut nodes = vec![start];
+        for pair in path.nodes.windows(2) {
+            let edge = self.edges(pair[0]).iter().filter(|edge| edge.to == pair[1])
+                .min_by_key(|edge| edge.weight)
+                .unwrap();
+            nodes.extend(edge.via.iter().copied());
+            nodes.push(pair[1]);
+        }
+
+        Some(Path { length: path.length, nodes })
+    }
+
+    // the groups of nodes connected by edges in either direction
+    pub fn components(&self) -> Vec<Vec<N>> {
+        let undirected = self.undirected();
+
+        components(self.nodes(), |node| undirected[&node].iter().copied().collect::<Vec<N>>())
+    }
+
+    // Replaces every corridor, a node with exactly two neighbours which is not kept, by a single
+    // edge between the nodes at its ends. Every simple path keeps its length, but there are far
+    // fewer of them to try, e.g. only the junctions of a maze are left.
+    // Corridors which lead nowhere, or only against the direction of their edges, are dropped
+    pub fn contract<K>(&self, keep: K) -> Graph<N> where K: Fn(N) -> bool {
+        let undirected = self.undirected();
+        let is_junction = |node: N| keep(node) || undirected[&node].len() != 2;
+
+        let mut graph: Graph<N> = Graph::new();
+        for from in self.nodes().filter(|&node| is_junction(node)) {
+            graph.add_node(from);
+
+            for first in self.edges(from) {
+                let mut edge = first.clone();
+                let mut previous = from;
+                let mut walked = true;
+                while !is_junction(edge.to) {
+                    // a corridor has one way on, besides the one it was entered from
+                    let Some(next) = self.edges(edge.to).iter().find(|e| e.to != previous) else {
+                        walked = false;
+                        break;
+                    };
+
+                    previous = edge.to;
+                    edge.via.push(edge.to);
+                    edge.via.extend(next.via.iter().copied());
+                    edge.to = next.to;
+                    edge.weight += next.weight;
+                }
+
+                if walked {
+                    graph.add_node(edge.to);
+                    graph.edges.get_mut(&from).unwrap().push(edge);
+                }
+            }
+        }
+
+        graph
+    }
+
+    // The longest path from start to end which visits no node twice. This tries every path,
+    // so it is only feasible for small graphs, like a contracted maze
+    pub fn longest_path(&self, start: N, end: N) -> Option<Path<N>> {
+        let nodes: Vec<N> = self.nodes().collect();
+        let index: HashMap<N, usize> = nodes.iter().enumerate().map(|(i, &node)| (node, i)).collect();
+        let (&start_index, &end_index) = (index.get(&start)?, index.get(&end)?);
+        let edges: Vec<Vec<(usize, &Edge<N>)>> = nodes.iter().map(|&node|
+            self.edges(node).iter().map(|edge| (index[&edge.to], edge)).collect()
+        ).collect();
+
+        let mut search = LongestPath { edges: &edges, end: end_index, visited: vec![false; nodes.len()], current: Vec::new(), best: None };
+        search.visit(start_index, 0);
+
+        search.best.map(|(length, path)| {
+            let mut nodes = vec![start];
+            for edge in path {
+                nodes.extend(edge.via.iter().copied());
+                nodes.push(edge.to);
+            }
+
+            Path { length, nodes }
+        })
+    }
+
+    // the neighbours of every node, following the edges in both directions
+    fn undirected(&self) -> HashMap<N, HashSet<N>> {
+        let mut undirected: HashMap<N, HashSet<N>> = self.nodes().map(|node| (node, HashSet::new())).collect();
+        for (&from, edges) in self.edges.iter() {
+            for edge in edges.iter().filter(|edge| edge.to != from) {
+                undirected.get_mut(&from).unwrap().insert(edge.to);
+                undirected.get_mut(&edge.to).unwrap().insert(from);
+            }
+        }
+
+        undirected
+    }
+}
+
+// the depth first search of longest_path, on the nodes by index
+struct LongestPath<'a, N> {
+    edges: &'a [Vec<(usize, &'a Edge<N>)>],
+    end: usize,
+    visited: Vec<bool>,
+    current: Vec<&'a Edge<N>>,
+    best: Option<(u64, Vec<&'a Edge<N>>)>
+}
+
+impl<'a, N> LongestPath<'a, N> {
+    fn visit(&mut self, node: usize, length: u64) {
+        if node == self.end {
+            if self.best.as_ref().is_none_or(|(best, _)| *best < length) {
+                self.best = Some((length, self.current.clone()));
+            }
+            return;
+        }
+
+        self.visited[node] = true;
+        for &(next, edge) in self.edges[node].iter() {
+            if self.visited[next] {
+                continue;
+            }
+
+            self.current.push(edge);
+            self.visit(next, length + edge.weight);
+            self.current.pop();
+        }
+        self.visited[node] = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::{Grid, Position};
+
+    use super::{astar, bfs, components, dijkstra, Graph};
+
+    fn maze() -> Grid<char> {
+        Grid::parse("#.#####\n#.....#\n#.###.#\n#.....#\n#.#####", |c, _| Ok(c)).unwrap()
+    }
+
+    fn open(grid: &Grid<char>, position: Position) -> Vec<Position> {
+        grid.neighbours4(position).filter(|&n| grid[n] != '#').collect()
+    }
+
+    #[test]
+    fn test_bfs_on_grid() {
+        let grid = maze();
+        let distances = bfs((1, 0), |p| open(&grid, p));
+
+        assert_eq!(distances[&(1, 4)], 4);
+        assert_eq!(distances[&(5, 2)], 6);
+        assert_eq!(distances.len(), 14);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // the direct edge is heavier than the way around
+        let edges = |node: u8| match node {
+            0 => vec![(1, 1), (2, 10)],
+            1 => vec![(2, 2)],
+            2 => vec![(3, 1)],
+            _ => vec![]
+        };
+
+        let path = dijkstra(0, |node| node == 3, edges).unwrap();
+        assert_eq!((path.length, path.nodes), (4, vec![0, 1, 2, 3]));
+        assert_eq!(dijkstra(3, |node| node == 0, edges), None);
+
+        let grid = maze();
+        let goal = (1, 4);
+        let manhattan = |(x, y): Position| (x.abs_diff(goal.0) + y.abs_diff(goal.1)) as u64;
+        let path = astar((1, 0), |p| p == goal, |p| open(&grid, p).into_iter().map(|n| (n, 1)), manhattan).unwrap();
+        assert_eq!(path.length, 4);
+        assert_eq!(path.nodes.first(), Some(&(1, 0)));
+        assert_eq!(path.nodes.last(), Some(&goal));
+    }
+
+    #[test]
+    fn test_components() {
+        let neighbours = |node: u8| match node {
+            0 | 2 => vec![1],
+            1 => vec![0, 2],
+            4 => vec![5],
+            5 => vec![4],
+            _ => vec![]
+        };
+        let mut groups: Vec<Vec<u8>> = components(0..6, neighbours).into_iter().map(|mut c| {
+            c.sort();
+            c
+        }).collect();
+        groups.sort();
+
+        assert_eq!(groups, vec![vec![0, 1, 2], vec![3], vec![4, 5]]);
+    }
+
+    #[test]
+    fn test_contract_and_longest_path() {
+        let grid = maze();
+        let graph = Graph::from_grid(&grid, |p, &c| (c != '#').then(|| open(&grid, p)));
+        let (start, end) = ((1, 0), (1, 4));
+
+        let contracted = graph.contract(|p| p == start || p == end);
+        // the start, the end and the two junctions at the ends of the loop
+        assert_eq!(contracted.len(), 4);
+
+        let longest = contracted.longest_path(start, end).unwrap();
+        assert_eq!(longest, graph.longest_path(start, end).unwrap());
+        assert_eq!(longest.length, 12);
+        assert_eq!(longest.nodes.len(), 13);
+        assert!(longest.nodes.windows(2).all(|pair| pair[0].0.abs_diff(pair[1].0) + pair[0].1.abs_diff(pair[1].1) == 1));
+
+        let shortest = contracted.shortest_path(start, end).unwrap();
+        assert_eq!(shortest.nodes, vec![(1, 0), (1, 1), (1, 2), (1, 3), (1, 4)]);
+        assert_eq!(contracted.components().len(), 1);
+    }
+}
@@ -0,0 +1,313 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::error::{Error, Result};
+use crate::geometry::{Direction, Point};
+use crate::input;
+
+// a position in a grid as (x, y), i.e. (column, row) with (0, 0) in the top left corner
+pub type Position = (usize, usize);
+
+const NEIGHBOURS_8: [(i64, i64); 8] = [(-1, -1), (0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0)];
+
+// A dense rectangular map, stored row by row
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "A {}x{} grid needs {} cells", width, height, width * height);
+
+        Grid { width, height, cells }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self where T: Clone {
+        Grid { width, height, cells: vec![value; width * height] }
+    }
+
+    // parse a character map, every char is turned into a cell. The slice of the char
+    // is handed over as well, so that errors can point at it
+    pub fn parse<F>(input: &str, cell: F) -> Result<Self> where F: Fn(char, &str) -> Result<T> {
+        Self::from_lines(&input::lines(input), cell)
+    }
+
+    // same as parse, for a map which is only a part of the input (e.g. one paragraph)
+    pub fn from_lines<F>(lines: &[&str], cell: F) -> Result<Self> where F: Fn(char, &str) -> Result<T> {
+        let mut cells: Vec<T> = Vec::new();
+        let mut width: Option<usize> = None;
+
+        for &line in lines {
+            let before = cells.len();
+            for (c, token) in input::chars(line) {
+                cells.push(cell(c, token)?);
+            }
+
+            let line_width = cells.len() - before;
+            match width {
+                None => width = Some(line_width),
+                Some(w) if w != line_width => return Err(Error::parse(line, format!("expected a row of length {}", w))),
+                Some(_) => {}
+            }
+        }
+
+        Ok(Grid { width: width.unwrap_or(0), height: lines.len(), cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    // the position if (x, y) is inside of the grid. Handy for walking with signed steps
+    pub fn position(&self, x: i64, y: i64) -> Option<Position> {
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            return None;
+        }
+
+        Some((x as usize, y as usize))
+    }
+
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        self.position(x, y).is_some()
+    }
+
+    // the grid position of a point, if it is inside of the grid
+    pub fn point(&self, point: Point<i64>) -> Option<Position> {
+        self.position(point.x, point.y)
+    }
+
+    // one step from position into the direction, if that is still inside of the grid
+    pub fn step(&self, position: Position, direction: Direction) -> Option<Position> {
+        let to = Point::new(position.0 as i64, position.1 as i64) + direction.vector();
+
+        self.point(to)
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        if position.0 >= self.width || position.1 >= self.height {
+            return None;
+        }
+
+        self.cells.get(position.1 * self.width + position.0)
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if position.0 >= self.width || position.1 >= self.height {
+            return None;
+        }
+
+        self.cells.get_mut(position.1 * self.width + position.0)
+    }
+
+    // all positions, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    // all cells with their position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    // first position with a cell matching the predicate
+    pub fn find<P>(&self, predicate: P) -> Option<Position> where P: Fn(&T) -> bool {
+        self.iter().find(|(_, cell)| predicate(cell)).map(|(position, _)| position)
+    }
+
+    // the up to 4 direct neighbours inside the grid: up, right, down, left
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL.into_iter().filter_map(move |direction| self.step(position, direction))
+    }
+
+    // the up to 8 neighbours inside the grid, including the diagonal ones
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        NEIGHBOURS_8.iter().filter_map(move |offset|
+            self.position(position.0 as i64 + offset.0, position.1 as i64 + offset.1)
+        )
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "Row {} is outside of a grid with {} rows", y, self.height);
+
+        &self.cells[y * self.width .. (y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        assert!(y < self.height, "Row {} is outside of a grid with {} rows", y, self.height);
+
+        &mut self.cells[y * self.width .. (y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks of an empty grid would not yield any row, which is what we want
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {} is outside of a grid with {} columns", x, self.width);
+
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U> where F: Fn(&T) -> U {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    // a new grid built from the cells of this one, new_width x new_height big. source
+    // tells for every new position which old position to take the cell from
+    fn rearrange<F>(&self, new_width: usize, new_height: usize, source: F) -> Self
+        where T: Clone, F: Fn(Position) -> Position {
+        let mut cells: Vec<T> = Vec::with_capacity(self.cells.len());
+        for y in 0..new_height {
+            for x in 0..new_width {
+                cells.push(self[source((x, y))].clone());
+            }
+        }
+
+        Grid { width: new_width, height: new_height, cells }
+    }
+
+    // rows become columns
+    pub fn transpose(&self) -> Self where T: Clone {
+        self.rearrange(self.height, self.width, |(x, y)| (y, x))
+    }
+
+    pub fn rotate_clockwise(&self) -> Self where T: Clone {
+        let height = self.height;
+
+        self.rearrange(self.height, self.width, |(x, y)| (y, height - 1 - x))
+    }
+
+    pub fn rotate_counter_clockwise(&self) -> Self where T: Clone {
+        let width = self.width;
+
+        self.rearrange(self.height, self.width, |(x, y)| (width - 1 - y, x))
+    }
+
+    // mirror left to right, every row is reversed
+    pub fn flip_horizontal(&self) -> Self where T: Clone {
+        let width = self.width;
+
+        self.rearrange(self.width, self.height, |(x, y)| (width - 1 - x, y))
+    }
+
+    // mirror top to bottom, the order of the rows is reversed
+    pub fn flip_vertical(&self) -> Self where T: Clone {
+        let height = self.height;
+
+        self.rearrange(self.width, self.height, |(x, y)| (x, height - 1 - y))
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        let (width, height) = (self.width, self.height);
+
+        self.get(position).unwrap_or_else(||
+            panic!("Position {:?} is outside of the {}x{} grid", position, width, height)
+        )
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+
+        self.get_mut(position).unwrap_or_else(||
+            panic!("Position {:?} is outside of the {}x{} grid", position, width, height)
+        )
+    }
+}
+
+// prints every cell with its own Display, one line per row
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+    use crate::Result;
+
+    fn parse(input: &str) -> Result<Grid<char>> {
+        Grid::parse(input, |c, _| Ok(c))
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = parse("ab\ncd\nef\n").unwrap();
+
+        assert_eq!(grid.width(), 2);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid[(1, 2)], 'f');
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.to_string(), "ab\ncd\nef");
+    }
+
+    #[test]
+    fn test_parse_ragged() {
+        let input = "abc\nde\n";
+        let e = parse(input).unwrap_err().locate(input);
+
+        assert_eq!(e.to_string(), "2:1: expected a row of length 3 at 'de'");
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = parse("abc\ndef").unwrap();
+
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(grid.columns().map(|c| c.collect::<String>()).collect::<Vec<String>>(), vec!["ad", "be", "cf"]);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = parse("abc\ndef\nghi").unwrap();
+
+        assert_eq!(grid.neighbours4((0, 0)).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8((2, 2)).collect::<Vec<_>>(), vec![(1, 1), (2, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn test_rotate_and_flip() {
+        let grid = parse("abc\ndef").unwrap();
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_clockwise().rotate_counter_clockwise(), grid);
+
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+        assert_eq!(grid.flip_horizontal().flip_vertical().to_string(), "fed\ncba");
+        assert_eq!(grid.flip_vertical().flip_vertical(), grid);
+    }
+}
@@ -0,0 +1,195 @@
+use std::any::Any;
+use std::fmt::Write;
+
+use crate::solution::{Part, Puzzle, Solution};
+
+// A day which can draw a picture of a part, for the runner's --image
+pub trait Draw: Solution {
+    // None if there is nothing to draw for the part
+    fn draw(input: &Self::Input, part: Part) -> Option<Image>;
+}
+
+// Object safe view on a Draw, like Puzzle is for Solution
+pub trait Export: Puzzle {
+    fn draw(&self, input: &dyn Any, part: Part) -> Option<Image>;
+}
+
+impl<D> Export for D where D: Draw + Sync, D::Input: 'static {
+    fn draw(&self, input: &dyn Any, part: Part) -> Option<Image> {
+        let input = input.downcast_ref::<D::Input>()
+            .expect("Input was parsed by a different puzzle");
+
+        D::draw(input, part)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+
+    // the colour as it is written in the puzzles, e.g. 0x70c710
+    pub fn from_hex(code: u32) -> Self {
+        Rgb((code >> 16) as u8, (code >> 8) as u8, code as u8)
+    }
+
+    fn css(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+// Either kind of picture, a day picks the one which suits what it draws
+#[derive(Debug)]
+pub enum Image {
+    Svg(Svg),
+    Ppm(Pixmap)
+}
+
+impl Image {
+    // the file extension the image has to be written to
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Image::Svg(_) => "svg",
+            Image::Ppm(_) => "ppm"
+        }
+    }
+
+    pub fn bytes(&self) -> Vec<u8> {
+        match self {
+            Image::Svg(svg) => svg.render().into_bytes(),
+            Image::Ppm(pixmap) => pixmap.ppm()
+        }
+    }
+}
+
+// A vector drawing in the coordinates of the puzzle. Lines keep their width however far
+// the drawing is scaled, so areas of millions of units still show their outlines
+#[derive(Debug)]
+pub struct Svg {
+    view_box: (f64, f64, f64, f64),
+    elements: Vec<String>
+}
+
+impl Svg {
+    pub fn new(min_x: f64, min_y: f64, width: f64, height: f64) -> Self {
+        Svg { view_box: (min_x, min_y, width, height), elements: Vec::new() }
+    }
+
+    pub fn polygon(&mut self, points: &[(f64, f64)], fill: Rgb, stroke: Rgb) {
+        self.elements.push(format!(
+            r#"<polygon points="{}" fill="{}" stroke="{}" stroke-width="1" vector-effect="non-scaling-stroke"/>"#,
+            Self::points(points), fill.css(), stroke.css()
+        ));
+    }
+
+    pub fn line(&mut self, from: (f64, f64), to: (f64, f64), stroke: Rgb) {
+        self.elements.push(format!(
+            r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}" stroke-width="1" vector-effect="non-scaling-stroke"/>"#,
+            from.0, from.1, to.0, to.1, stroke.css()
+        ));
+    }
+
+    pub fn rect(&mut self, from: (f64, f64), size: (f64, f64), fill: Option<Rgb>, stroke: Rgb) {
+        let fill = fill.map(|f| f.css()).unwrap_or(String::from("none"));
+        self.elements.push(format!(
+            r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}" stroke="{}" stroke-width="1" vector-effect="non-scaling-stroke"/>"#,
+            from.0, from.1, size.0, size.1, fill, stroke.css()
+        ));
+    }
+
+    pub fn circle(&mut self, center: (f64, f64), radius: f64, fill: Rgb) {
+        self.elements.push(format!(
+            r#"<circle cx="{}" cy="{}" r="{}" fill="{}"/>"#,
+            center.0, center.1, radius, fill.css()
+        ));
+    }
+
+    // the document, 800 pixels on its longer side
+    pub fn render(&self) -> String {
+        let (min_x, min_y, width, height) = self.view_box;
+        let scale = 800. / width.max(height).max(f64::MIN_POSITIVE);
+
+        let mut out = String::new();
+        writeln!(out, r#"<svg xmlns="http://www.w3.org/2000/svg" width="{:.0}" height="{:.0}" viewBox="{} {} {} {}">"#,
+                 (width * scale).max(1.), (height * scale).max(1.), min_x, min_y, width, height).unwrap();
+        for element in self.elements.iter() {
+            writeln!(out, "  {}", element).unwrap();
+        }
+        out.push_str("</svg>\n");
+
+        out
+    }
+
+    fn points(points: &[(f64, f64)]) -> String {
+        let points: Vec<String> = points.iter().map(|(x, y)| format!("{},{}", x, y)).collect();
+
+        points.join(" ")
+    }
+}
+
+// A picture made of pixels, written as binary PPM (P6)
+#[derive(Debug)]
+pub struct Pixmap {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>
+}
+
+impl Pixmap {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Pixmap { width, height, pixels: vec![background; width * height] }
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, colour: Rgb) {
+        assert!(x < self.width && y < self.height, "({}, {}) is outside of the {}x{} picture", x, y, self.width, self.height);
+
+        self.pixels[y * self.width + x] = colour;
+    }
+
+    // a square of size pixels, for pictures which draw every tile of a grid bigger
+    pub fn fill(&mut self, x: usize, y: usize, size: usize, colour: Rgb) {
+        for dy in 0..size {
+            for dx in 0..size {
+                self.set(x + dx, y + dy, colour);
+            }
+        }
+    }
+
+    pub fn ppm(&self) -> Vec<u8> {
+        let mut out = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        for pixel in self.pixels.iter() {
+            out.extend([pixel.0, pixel.1, pixel.2]);
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Pixmap, Rgb, Svg};
+
+    #[test]
+    fn test_ppm() {
+        let mut pixmap = Pixmap::new(2, 1, Rgb::WHITE);
+        pixmap.set(1, 0, Rgb::from_hex(0x70c710));
+
+        assert_eq!(pixmap.ppm(), b"P6\n2 1\n255\n\xff\xff\xff\x70\xc7\x10".to_vec());
+    }
+
+    #[test]
+    fn test_svg() {
+        let mut svg = Svg::new(0., -2., 4., 2.);
+        svg.polygon(&[(0., 0.), (4., 0.), (4., -2.)], Rgb::WHITE, Rgb::BLACK);
+        svg.circle((1., -1.), 0.5, Rgb(255, 0, 0));
+
+        assert_eq!(svg.render(), concat!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"800\" height=\"400\" viewBox=\"0 -2 4 2\">\n",
+            "  <polygon points=\"0,0 4,0 4,-2\" fill=\"#ffffff\" stroke=\"#000000\" stroke-width=\"1\" vector-effect=\"non-scaling-stroke\"/>\n",
+            "  <circle cx=\"1\" cy=\"-1\" r=\"0.5\" fill=\"#ff0000\"/>\n",
+            "</svg>\n"
+        ));
+    }
+}
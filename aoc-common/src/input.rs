@@ -0,0 +1,122 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+
+// where the input of a puzzle comes from
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf)
+}
+
+impl Source {
+    // "-" is stdin, any other argument a path. Without an argument the input of the
+    // day is looked up in the input directory, as day-05.txt or day-05.example.txt
+    pub fn resolve(argument: Option<&Path>, inputs: &Path, day: u8, example: bool) -> Self {
+        match argument {
+            Some(path) if path == Path::new("-") => Source::Stdin,
+            Some(path) => Source::File(path.to_path_buf()),
+            None => Source::File(default_path(inputs, day, example))
+        }
+    }
+
+    // the name to report errors with
+    pub fn path(&self) -> &Path {
+        match self {
+            Source::Stdin => Path::new("<stdin>"),
+            Source::File(path) => path
+        }
+    }
+
+    pub fn read(&self) -> Result<String> {
+        match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map_err(|source|
+                    Error::Io { path: self.path().to_path_buf(), source }
+                )?;
+
+                Ok(input)
+            },
+            Source::File(path) => read_input(path)
+        }
+    }
+}
+
+// the conventional location of the input of a day, e.g. inputs/day-05.txt
+pub fn default_path(inputs: &Path, day: u8, example: bool) -> PathBuf {
+    if example {
+        inputs.join(format!("day-{:02}.example.txt", day))
+    } else {
+        inputs.join(format!("day-{:02}.txt", day))
+    }
+}
+
+pub fn read_input<P: AsRef<Path>>(path: P) -> Result<String> {
+    let path = path.as_ref();
+
+    fs::read_to_string(path).map_err(|source|
+        Error::Io { path: path.to_path_buf(), source }
+    )
+}
+
+// all lines of the input without surrounding blank lines
+pub fn lines(input: &str) -> Vec<&str> {
+    input.trim().lines().collect()
+}
+
+// blocks of lines which are separated by an empty line
+pub fn paragraphs<'a, 'b>(lines: &'b [&'a str]) -> Vec<&'b [&'a str]> {
+    lines.split(|line| line.is_empty()).collect()
+}
+
+// the token as a number, or a parse error pointing at it
+pub fn number<T: FromStr>(token: &str) -> Result<T> {
+    token.parse::<T>().map_err(|_|
+        Error::parse(token, "expected a number")
+    )
+}
+
+// the two parts around the first separator, e.g. "Game 1: 3 blue" split at ": "
+pub fn split_pair<'a>(line: &'a str, separator: &str) -> Result<(&'a str, &'a str)> {
+    line.split_once(separator).ok_or_else(||
+        Error::parse(line, format!("expected two parts separated by '{}'", separator))
+    )
+}
+
+// every char of the line together with the slice it came from, so that parse errors
+// can point at a single char
+pub fn chars(line: &str) -> impl Iterator<Item = (char, &str)> {
+    line.char_indices().map(|(i, c)|
+        (c, &line[i..i + c.len_utf8()])
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::{lines, paragraphs, Source};
+
+    #[test]
+    fn test_paragraphs() {
+        let input = "\nseeds: 1 2\n\na\nb\r\n\nc\n\n";
+        let lines = lines(input);
+        let blocks = paragraphs(&lines);
+
+        assert_eq!(blocks, vec![&["seeds: 1 2"][..], &["a", "b"][..], &["c"][..]]);
+    }
+
+    #[test]
+    fn test_resolve_source() {
+        let inputs = Path::new("inputs");
+
+        assert_eq!(Source::resolve(Some(Path::new("-")), inputs, 5, false), Source::Stdin);
+        assert_eq!(Source::resolve(Some(Path::new("my.txt")), inputs, 5, true), Source::File(PathBuf::from("my.txt")));
+        assert_eq!(Source::resolve(None, inputs, 5, false), Source::File(PathBuf::from("inputs/day-05.txt")));
+        assert_eq!(Source::resolve(None, inputs, 5, true), Source::File(PathBuf::from("inputs/day-05.example.txt")));
+    }
+}
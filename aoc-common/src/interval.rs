@@ -0,0 +1,337 @@
+use std::fmt;
+use std::ops::Range;
+
+// A set of integers kept as sorted half-open ranges, which neither overlap nor touch. So two
+// sets with the same members are always equal, however they were built
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct IntervalSet {
+    ranges: Vec<Range<i64>>
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_range(range: Range<i64>) -> Self {
+        let mut set = IntervalSet::new();
+        set.insert(range);
+
+        set
+    }
+
+    pub fn ranges(&self) -> &[Range<i64>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    // the number of members
+    pub fn len(&self) -> u64 {
+        self.ranges.iter().map(|r| r.end.abs_diff(r.start)).sum()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.ranges.last().map(|r| r.end - 1)
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        // the first range which ends after the value is the only one which can hold it
+        let i = self.ranges.partition_point(|r| r.end <= value);
+
+        self.ranges.get(i).is_some_and(|r| r.start <= value)
+    }
+
+    pub fn insert(&mut self, range: Range<i64>) {
+        if range.is_empty() {
+            return;
+        }
+
+        // the ranges which overlap or touch the new one are merged into it
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+        let mut merged = range;
+        if first < last {
+            merged.start = merged.start.min(self.ranges[first].start);
+            merged.end = merged.end.max(self.ranges[last - 1].end);
+        }
+
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut union = self.clone();
+        for range in other.ranges.iter() {
+            union.insert(range.clone());
+        }
+
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges: Vec<Range<i64>> = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+
+            // the range which ends first cannot overlap anything after the other one
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        IntervalSet { ranges }
+    }
+
+    // the members of self which are not in other
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges: Vec<Range<i64>> = Vec::new();
+        let mut j = 0;
+        for range in self.ranges.iter() {
+            let mut start = range.start;
+            while j < other.ranges.len() && other.ranges[j].end <= start {
+                j += 1;
+            }
+
+            let mut k = j;
+            while k < other.ranges.len() && other.ranges[k].start < range.end {
+                if other.ranges[k].start > start {
+                    ranges.push(start..other.ranges[k].start);
+                }
+                start = start.max(other.ranges[k].end);
+                k += 1;
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+
+        IntervalSet { ranges }
+    }
+
+    // every member moved by delta
+    pub fn shift(&self, delta: i64) -> IntervalSet {
+        let ranges = self.ranges.iter().map(|r| r.start + delta..r.end + delta).collect();
+
+        IntervalSet { ranges }
+    }
+
+    // the members below at and the ones from at on
+    pub fn split_at(&self, at: i64) -> (IntervalSet, IntervalSet) {
+        let below = IntervalSet::from_range(i64::MIN..at);
+
+        (self.intersection(&below), self.difference(&below))
+    }
+
+    // how many members lie within the range
+    pub fn count_in(&self, range: Range<i64>) -> u64 {
+        self.intersection(&IntervalSet::from_range(range)).len()
+    }
+}
+
+impl FromIterator<Range<i64>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Range<i64>>>(ranges: I) -> Self {
+        let mut set = IntervalSet::new();
+        for range in ranges {
+            set.insert(range);
+        }
+
+        set
+    }
+}
+
+// single members, like the empty rows of an image
+impl FromIterator<i64> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = i64>>(values: I) -> Self {
+        values.into_iter().map(|v| v..v + 1).collect()
+    }
+}
+
+impl fmt::Display for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ranges: Vec<String> = self.ranges.iter().map(|r| format!("{}..{}", r.start, r.end)).collect();
+
+        write!(f, "{{{}}}", ranges.join(", "))
+    }
+}
+
+// A box of integer points with a half-open range on every axis, like all parts with ratings
+// from 1 to 4000 in each of their four categories
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Cuboid<const D: usize> {
+    pub sides: [Range<i64>; D]
+}
+
+impl<const D: usize> Cuboid<D> {
+    pub fn new(sides: [Range<i64>; D]) -> Self {
+        Cuboid { sides }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sides.iter().any(|side| side.is_empty())
+    }
+
+    // the number of points in the box
+    pub fn volume(&self) -> u128 {
+        if self.is_empty() {
+            return 0;
+        }
+
+        self.sides.iter().map(|side| side.end.abs_diff(side.start) as u128).product()
+    }
+
+    pub fn contains(&self, point: [i64; D]) -> bool {
+        self.sides.iter().zip(point).all(|(side, p)| side.contains(&p))
+    }
+
+    pub fn intersection(&self, other: &Cuboid<D>) -> Option<Cuboid<D>> {
+        let sides = std::array::from_fn(|axis|
+            self.sides[axis].start.max(other.sides[axis].start)..self.sides[axis].end.min(other.sides[axis].end)
+        );
+        let intersection = Cuboid { sides };
+
+        (!intersection.is_empty()).then_some(intersection)
+    }
+
+    // the parts of the box below at and from at on along the axis, None if a part is empty
+    pub fn split_at(&self, axis: usize, at: i64) -> (Option<Cuboid<D>>, Option<Cuboid<D>>) {
+        let side = &self.sides[axis];
+        let at = at.max(side.start).min(side.end.max(side.start));
+
+        let mut below = self.clone();
+        below.sides[axis].end = at;
+        let mut above = self.clone();
+        above.sides[axis].start = at;
+
+        ((!below.is_empty()).then_some(below), (!above.is_empty()).then_some(above))
+    }
+
+    // the points of self outside of other, as disjoint boxes. One axis after the other, the
+    // slices on either side of other are cut off, what is left in the end is the overlap
+    pub fn difference(&self, other: &Cuboid<D>) -> Vec<Cuboid<D>> {
+        if self.intersection(other).is_none() {
+            return if self.is_empty() { Vec::new() } else { vec![self.clone()] };
+        }
+
+        let mut pieces: Vec<Cuboid<D>> = Vec::new();
+        let mut rest = self.clone();
+        for axis in 0..D {
+            let (below, inside) = rest.split_at(axis, other.sides[axis].start);
+            pieces.extend(below);
+            let (inside, above) = inside.unwrap().split_at(axis, other.sides[axis].end);
+            pieces.extend(above);
+            rest = inside.unwrap();
+        }
+
+        pieces
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::ops::Range;
+
+    use proptest::prelude::*;
+
+    use super::{Cuboid, IntervalSet};
+
+    // small ranges on a small line, so that they overlap and touch often
+    fn set() -> impl Strategy<Value = (IntervalSet, BTreeSet<i64>)> {
+        prop::collection::vec((-20..20i64, 0..8i64), 0..6).prop_map(|ranges| {
+            let ranges: Vec<Range<i64>> = ranges.into_iter().map(|(start, length)| start..start + length).collect();
+            let members: BTreeSet<i64> = ranges.iter().flat_map(|r| r.clone()).collect();
+
+            (ranges.into_iter().collect(), members)
+        })
+    }
+
+    fn members(set: &IntervalSet) -> BTreeSet<i64> {
+        set.ranges().iter().flat_map(|r| r.clone()).collect()
+    }
+
+    fn cuboid() -> impl Strategy<Value = Cuboid<3>> {
+        prop::array::uniform3((-4..4i64, 0..5i64)).prop_map(|sides|
+            Cuboid::new(sides.map(|(start, length)| start..start + length))
+        )
+    }
+
+    fn points(cuboid: &Cuboid<3>) -> BTreeSet<[i64; 3]> {
+        let mut points = BTreeSet::new();
+        for x in cuboid.sides[0].clone() {
+            for y in cuboid.sides[1].clone() {
+                for z in cuboid.sides[2].clone() {
+                    points.insert([x, y, z]);
+                }
+            }
+        }
+
+        points
+    }
+
+    #[test]
+    fn test_insert_merges() {
+        let set: IntervalSet = [5..8, 0..2, 2..3, 7..10].into_iter().collect();
+
+        assert_eq!(set.ranges(), &[0..3, 5..10]);
+        assert_eq!(set.to_string(), "{0..3, 5..10}");
+        assert_eq!((set.len(), set.min(), set.max()), (8, Some(0), Some(9)));
+    }
+
+    proptest! {
+        #[test]
+        fn test_set_operations((a, a_members) in set(), (b, b_members) in set(), delta in -30..30i64, at in -25..25i64) {
+            prop_assert_eq!(members(&a), a_members.clone());
+            prop_assert!(a.ranges().windows(2).all(|pair| pair[0].end < pair[1].start));
+            prop_assert_eq!(a.len(), a_members.len() as u64);
+
+            prop_assert_eq!(members(&a.union(&b)), a_members.union(&b_members).copied().collect::<BTreeSet<i64>>());
+            prop_assert_eq!(members(&a.intersection(&b)), a_members.intersection(&b_members).copied().collect::<BTreeSet<i64>>());
+            prop_assert_eq!(members(&a.difference(&b)), a_members.difference(&b_members).copied().collect::<BTreeSet<i64>>());
+            prop_assert_eq!(members(&a.shift(delta)), a_members.iter().map(|m| m + delta).collect::<BTreeSet<i64>>());
+
+            let (below, above) = a.split_at(at);
+            prop_assert_eq!(members(&below), a_members.iter().copied().filter(|&m| m < at).collect::<BTreeSet<i64>>());
+            prop_assert_eq!(members(&above), a_members.iter().copied().filter(|&m| m >= at).collect::<BTreeSet<i64>>());
+
+            for value in -30..30 {
+                prop_assert_eq!(a.contains(value), a_members.contains(&value));
+            }
+        }
+
+        #[test]
+        fn test_cuboids(a in cuboid(), b in cuboid(), axis in 0..3usize, at in -5..5i64) {
+            let (a_points, b_points) = (points(&a), points(&b));
+            prop_assert_eq!(a.volume(), a_points.len() as u128);
+
+            let overlap = a.intersection(&b).map(|c| points(&c)).unwrap_or_default();
+            prop_assert_eq!(overlap, a_points.intersection(&b_points).copied().collect::<BTreeSet<[i64; 3]>>());
+
+            let pieces = a.difference(&b);
+            let piece_points: Vec<BTreeSet<[i64; 3]>> = pieces.iter().map(points).collect();
+            prop_assert_eq!(piece_points.iter().map(|p| p.len()).sum::<usize>(), piece_points.iter().flatten().collect::<BTreeSet<_>>().len());
+            prop_assert_eq!(piece_points.into_iter().flatten().collect::<BTreeSet<[i64; 3]>>(), a_points.difference(&b_points).copied().collect::<BTreeSet<[i64; 3]>>());
+
+            let (below, above) = a.split_at(axis, at);
+            let below = below.map(|c| points(&c)).unwrap_or_default();
+            let above = above.map(|c| points(&c)).unwrap_or_default();
+            prop_assert_eq!(below, a_points.iter().copied().filter(|p| p[axis] < at).collect::<BTreeSet<[i64; 3]>>());
+            prop_assert_eq!(above, a_points.iter().copied().filter(|p| p[axis] >= at).collect::<BTreeSet<[i64; 3]>>());
+            for p in a_points.iter() {
+                prop_assert!(a.contains(*p));
+            }
+        }
+    }
+}
@@ -0,0 +1,23 @@
+pub mod cycle;
+pub mod error;
+pub mod examples;
+pub mod generate;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod interval;
+pub mod number;
+pub mod parallel;
+pub mod parse;
+pub mod query;
+pub mod random;
+pub mod solution;
+pub mod trace;
+pub mod visualize;
+
+pub use error::{Error, Result};
+pub use geometry::{Direction, Point};
+pub use grid::Grid;
+pub use solution::{Answer, Part, Puzzle, Solution};
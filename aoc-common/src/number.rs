@@ -0,0 +1,229 @@
+use num::rational::Ratio;
+use num::{Integer, Signed, Zero};
+
+pub use num::{BigInt, ToPrimitive};
+
+// Fractions of any integer type, reduced after every operation. i128 is enough for a few
+// multiplications of puzzle numbers, longer calculations need BigInt
+pub type Rational<T> = Ratio<T>;
+
+// the greatest common divisor of all values, zero if there are none
+pub fn gcd_all<T, I>(values: I) -> T where T: Integer + Clone, I: IntoIterator<Item = T> {
+    values.into_iter().fold(T::zero(), |gcd, value| gcd.gcd(&value))
+}
+
+// the least common multiple of all values, one if there are none
+pub fn lcm_all<T, I>(values: I) -> T where T: Integer + Clone, I: IntoIterator<Item = T> {
+    values.into_iter().fold(T::one(), |lcm, value| lcm.lcm(&value))
+}
+
+// the x in 0..modulus with a * x = 1 modulo modulus, if a and the modulus are coprime. The
+// extended Euclidean algorithm with the size of the coefficients and their signs kept apart, so
+// it works with unsigned types as well. The signs alternate and the sizes stay below the modulus
+pub fn mod_inverse<T: Integer + Clone>(a: &T, modulus: &T) -> Option<T> {
+    let (mut r0, mut r1) = (modulus.clone(), a.mod_floor(modulus));
+    let (mut t0, mut t1) = (T::zero(), T::one());
+    let mut t0_negative = true;
+    while !r1.is_zero() {
+        let (q, r2) = r0.div_rem(&r1);
+        let t2 = t0 + q * t1.clone();
+        (r0, r1) = (r1, r2);
+        (t0, t1) = (t1, t2);
+        t0_negative = !t0_negative;
+    }
+
+    if !r0.is_one() {
+        return None;
+    }
+
+    if t0_negative && !t0.is_zero() {
+        Some(modulus.clone() - t0.mod_floor(modulus))
+    } else {
+        Some(t0.mod_floor(modulus))
+    }
+}
+
+// the x with x = remainder modulo modulus for every pair, as the remainder modulo the lcm of
+// all moduli. The moduli do not have to be coprime, None if the congruences contradict each
+// other. The lcm and the products below it have to fit into T
+pub fn crt<T, I>(congruences: I) -> Option<(T, T)> where T: Integer + Clone, I: IntoIterator<Item = (T, T)> {
+    let mut result = (T::zero(), T::one());
+    for (remainder, modulus) in congruences {
+        let (r1, m1) = result;
+        let r2 = remainder.mod_floor(&modulus);
+
+        // r1 + m1 * k = r2 modulo m2 has a solution if the gcd divides the difference
+        let gcd = m1.gcd(&modulus);
+        let difference = (r2 + modulus.clone() - r1.mod_floor(&modulus)).mod_floor(&modulus);
+        if !difference.is_multiple_of(&gcd) {
+            return None;
+        }
+
+        let m2 = modulus / gcd.clone();
+        let inverse = mod_inverse(&(m1.clone() / gcd.clone()), &m2)?;
+        let k = ((difference / gcd) * inverse).mod_floor(&m2);
+        result = (r1 + m1.clone() * k, m1 * m2);
+    }
+
+    Some(result)
+}
+
+// the largest r with r * r <= n, for a non-negative n. Newton's method from above, starting
+// low enough that nothing overflows
+pub fn isqrt<T: Integer + Clone>(n: &T) -> T {
+    let two = T::one() + T::one();
+    if *n < two {
+        return n.clone();
+    }
+
+    let mut x = n.clone() / two.clone() + T::one();
+    let mut y = (x.clone() + n.clone() / x.clone()) / two.clone();
+    while y < x {
+        x = y;
+        y = (x.clone() + n.clone() / x.clone()) / two.clone();
+    }
+
+    x
+}
+
+// the integers x with a * x^2 + b * x + c < 0 for a positive a, as the smallest and the largest
+// of them. With the integer square root the roots are off by a little, so they are moved to the
+// exact bounds afterwards, a step or two at most
+pub fn quadratic_below_zero<T: Integer + Signed + Clone>(a: &T, b: &T, c: &T) -> Option<(T, T)> {
+    let value = |x: &T| a.clone() * x.clone() * x.clone() + b.clone() * x.clone() + c.clone();
+    let below = |x: &T| value(x).is_negative();
+
+    let discriminant = b.clone() * b.clone() - (T::one() + T::one()) * (T::one() + T::one()) * a.clone() * c.clone();
+    if !discriminant.is_positive() {
+        return None;
+    }
+
+    let root = isqrt(&discriminant);
+    let twice_a = a.clone() + a.clone();
+    let mut low = (-b.clone() - root.clone()).div_floor(&twice_a);
+    let mut high = (-b.clone() + root).div_floor(&twice_a);
+    while !below(&low) && low <= high {
+        low = low + T::one();
+    }
+    while below(&(low.clone() - T::one())) {
+        low = low - T::one();
+    }
+    while !below(&high) && high >= low {
+        high = high - T::one();
+    }
+    while below(&(high.clone() + T::one())) {
+        high = high + T::one();
+    }
+
+    (low <= high && below(&low)).then_some((low, high))
+}
+
+// the x with a * x = b for a square a, given as the rows of a with the entry of b at the end.
+// Gauss-Jordan elimination, None if a is singular
+pub fn solve<T: Integer + Clone>(mut rows: Vec<Vec<Rational<T>>>) -> Option<Vec<Rational<T>>> {
+    let n = rows.len();
+    for column in 0..n {
+        let pivot = (column..n).find(|&row| !rows[row][column].is_zero())?;
+        rows.swap(column, pivot);
+
+        let pivot = rows[column][column].clone();
+        for entry in rows[column].iter_mut() {
+            *entry = entry.clone() / pivot.clone();
+        }
+        for row in 0..n {
+            if row == column || rows[row][column].is_zero() {
+                continue;
+            }
+
+            let factor = rows[row][column].clone();
+            let pivot_row = rows[column].clone();
+            for (entry, pivot_entry) in rows[row].iter_mut().zip(pivot_row).skip(column) {
+                *entry = entry.clone() - factor.clone() * pivot_entry;
+            }
+        }
+    }
+
+    Some(rows.into_iter().map(|row| row[n].clone()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::{crt, gcd_all, isqrt, lcm_all, mod_inverse, quadratic_below_zero, solve, BigInt, Rational};
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(lcm_all([2u64, 6, 4]), 12);
+        assert_eq!(gcd_all([12i128, -18, 30]), 6);
+        assert_eq!(crt([(2u64, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt([(1i128, 4), (2, 6)]), None);
+        assert_eq!(isqrt(&u64::MAX), 4294967295);
+        assert_eq!(isqrt(&BigInt::from(10).pow(40)), BigInt::from(10).pow(20));
+
+        // the first race of day 6, x * (7 - x) > 9
+        assert_eq!(quadratic_below_zero(&1i128, &-7, &9), Some((2, 5)));
+        assert_eq!(quadratic_below_zero(&1i128, &-4, &4), None);
+    }
+
+    proptest! {
+        #[test]
+        fn test_divisors(values in prop::collection::vec(1..60u64, 0..5)) {
+            let gcd = (1..=60).rev().find(|d| values.iter().all(|v| v % d == 0)).unwrap();
+            let lcm = (1..).find(|m| values.iter().all(|v| m % v == 0)).unwrap();
+
+            prop_assert_eq!(gcd_all(values.iter().copied()), if values.is_empty() { 0 } else { gcd });
+            prop_assert_eq!(lcm_all(values.iter().copied()), lcm);
+            prop_assert_eq!(lcm_all(values.iter().map(|&v| BigInt::from(v))), BigInt::from(lcm));
+        }
+
+        #[test]
+        fn test_crt(congruences in prop::collection::vec((0..30u64, 1..12u64), 0..4)) {
+            let lcm: u64 = lcm_all(congruences.iter().map(|&(_, m)| m));
+            let expected = (0..lcm).find(|x| congruences.iter().all(|&(r, m)| x % m == r % m));
+
+            prop_assert_eq!(crt(congruences.iter().copied()), expected.map(|x| (x, lcm)));
+            let signed = congruences.iter().map(|&(r, m)| (r as i128 - 15, m as i128));
+            let expected = (0..lcm as i128).find(|x| congruences.iter().all(|&(r, m)| (x - (r as i128 - 15)).rem_euclid(m as i128) == 0));
+            prop_assert_eq!(crt(signed), expected.map(|x| (x, lcm as i128)));
+        }
+
+        #[test]
+        fn test_inverse(a in 0..100u64, modulus in 1..100u64) {
+            let expected = (0..modulus).find(|x| a * x % modulus == 1 % modulus);
+
+            prop_assert_eq!(mod_inverse(&a, &modulus), expected);
+        }
+
+        #[test]
+        fn test_isqrt(n in any::<u64>()) {
+            let r = isqrt(&n) as u128;
+
+            prop_assert!(r * r <= n as u128 && (r + 1) * (r + 1) > n as u128);
+            prop_assert_eq!(isqrt(&BigInt::from(n)), BigInt::from(r));
+        }
+
+        #[test]
+        fn test_quadratic(a in 1..5i128, b in -40..40i128, c in -40..40i128) {
+            let below: Vec<i128> = (-100..100).filter(|x| a * x * x + b * x + c < 0).collect();
+            let expected = below.first().map(|&low| (low, *below.last().unwrap()));
+
+            prop_assert_eq!(quadratic_below_zero(&a, &b, &c), expected);
+        }
+
+        #[test]
+        fn test_solve(a in prop::array::uniform3(prop::array::uniform3(-5..5i128)), x in prop::array::uniform3(-20..20i128)) {
+            let det = a[0][0] * (a[1][1] * a[2][2] - a[1][2] * a[2][1])
+                - a[0][1] * (a[1][0] * a[2][2] - a[1][2] * a[2][0])
+                + a[0][2] * (a[1][0] * a[2][1] - a[1][1] * a[2][0]);
+            let rows = a.iter().map(|row| {
+                let b: i128 = row.iter().zip(x).map(|(a, x)| a * x).sum();
+
+                row.iter().chain([&b]).map(|&v| Rational::from_integer(v)).collect()
+            }).collect();
+
+            let expected = (det != 0).then(|| x.iter().map(|&v| Rational::from_integer(v)).collect());
+            prop_assert_eq!(solve(rows), expected);
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+// how many threads the solutions may use, 0 means one per core
+static THREADS: AtomicUsize = AtomicUsize::new(0);
+
+pub fn set_threads(threads: usize) {
+    THREADS.store(threads, Ordering::Relaxed);
+}
+
+pub fn threads() -> usize {
+    match THREADS.load(Ordering::Relaxed) {
+        0 => thread::available_parallelism().map(|n| n.get()).unwrap_or(1),
+        n => n
+    }
+}
+
+// f applied to every item, the results are in the order of the items no matter which thread
+// computed them, so this gives exactly what items.iter().map(f) gives.
+// The workers take the next item as soon as they are done with one, so items which take much
+// longer than others do not leave the other threads waiting
+pub fn map<T, R, F>(items: &[T], f: F) -> Vec<R> where T: Sync, R: Send, F: Fn(&T) -> R + Sync {
+    let workers = threads().min(items.len());
+    if workers <= 1 {
+        return items.iter().map(f).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let done: Mutex<Vec<(usize, R)>> = Mutex::new(Vec::with_capacity(items.len()));
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                let mut results: Vec<(usize, R)> = Vec::new();
+                loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    if i >= items.len() {
+                        break;
+                    }
+                    results.push((i, f(&items[i])));
+                }
+
+                done.lock().unwrap().extend(results);
+            });
+        }
+    });
+
+    let mut done = done.into_inner().unwrap();
+    done.sort_by_key(|&(i, _)| i);
+
+    done.into_iter().map(|(_, result)| result).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::map;
+
+    #[test]
+    fn test_map_keeps_order() {
+        let items: Vec<u64> = (0..1000).collect();
+
+        let squares = map(&items, |&n| {
+            // uneven work, so the items do not finish in order
+            (0..n % 7 * 1000).fold(n * n, |acc, _| acc)
+        });
+
+        assert_eq!(squares, items.iter().map(|&n| n * n).collect::<Vec<u64>>());
+        assert_eq!(map(&Vec::<u64>::new(), |&n| n), Vec::<u64>::new());
+    }
+}
@@ -0,0 +1,278 @@
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::input;
+
+// A cursor over a line or a block of the input. Every method takes what it recognised off the
+// front, and errors point at the text where something else was expected, so they get a line and
+// a column like any other parse error. The methods taking a closure combine smaller parsers,
+// e.g. p.separated(",", Parser::integer) for "1,-2,3"
+#[derive(Clone, Copy, Debug)]
+pub struct Parser<'a> {
+    text: &'a str,
+    rest: &'a str
+}
+
+// runs f on the whole text, anything it leaves over is an error
+pub fn all<'a, T, F>(text: &'a str, f: F) -> Result<T> where F: FnOnce(&mut Parser<'a>) -> Result<T> {
+    let mut p = Parser::new(text);
+    let value = f(&mut p)?;
+    p.finish()?;
+
+    Ok(value)
+}
+
+// the label of a block like "seed-to-soil map:" and the lines below it
+pub fn section<'a, 'b>(lines: &'b [&'a str]) -> Result<(&'a str, &'b [&'a str])> {
+    let Some((&header, body)) = lines.split_first() else {
+        return Err(Error::parse("", "expected a section"));
+    };
+    let Some(label) = header.strip_suffix(':') else {
+        return Err(Error::parse(header, "expected a section header ending with ':'"));
+    };
+
+    Ok((label, body))
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Parser { text, rest: text }
+    }
+
+    // what is not parsed yet
+    pub fn rest(&self) -> &'a str {
+        self.rest
+    }
+
+    // how many bytes are parsed already, e.g. the column of the next token on a line
+    pub fn offset(&self) -> usize {
+        self.text.len() - self.rest.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest.chars().next()
+    }
+
+    // an error pointing at the next word, or at the end of the text if nothing is left
+    pub fn error(&self, message: impl Into<String>) -> Error {
+        let end = self.rest.find(char::is_whitespace).unwrap_or(self.rest.len());
+
+        Error::parse(&self.rest[..end], message)
+    }
+
+    // fails if anything besides trailing whitespace is left
+    pub fn finish(&mut self) -> Result<()> {
+        self.whitespace();
+        if !self.is_empty() {
+            return Err(Error::parse(self.rest, "unexpected text"));
+        }
+
+        Ok(())
+    }
+
+    // the next char with the slice it came from
+    pub fn char(&mut self) -> Result<(char, &'a str)> {
+        let Some(c) = self.peek() else {
+            return Err(self.error("unexpected end of the line"));
+        };
+
+        Ok((c, self.take(c.len_utf8())))
+    }
+
+    // takes the literal if the text continues with it
+    pub fn eat(&mut self, literal: &str) -> bool {
+        match self.rest.strip_prefix(literal) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            },
+            None => false
+        }
+    }
+
+    pub fn expect(&mut self, literal: &str) -> Result<()> {
+        if !self.eat(literal) {
+            return Err(self.error(format!("expected '{}'", literal)));
+        }
+
+        Ok(())
+    }
+
+    // the longest prefix whose chars all satisfy f, possibly empty
+    pub fn take_while<F>(&mut self, f: F) -> &'a str where F: Fn(char) -> bool {
+        let end = self.rest.find(|c| !f(c)).unwrap_or(self.rest.len());
+
+        self.take(end)
+    }
+
+    // spaces and tabs, the whitespace within a line
+    pub fn spaces(&mut self) {
+        self.take_while(|c| c == ' ' || c == '\t');
+    }
+
+    pub fn whitespace(&mut self) {
+        self.take_while(char::is_whitespace);
+    }
+
+    // letters and digits, like the name of a workflow or a node
+    pub fn word(&mut self) -> Result<&'a str> {
+        let word = self.take_while(|c| c.is_ascii_alphanumeric());
+        if word.is_empty() {
+            return Err(self.error("expected a name"));
+        }
+
+        Ok(word)
+    }
+
+    // digits with an optional sign
+    pub fn integer<T: FromStr>(&mut self) -> Result<T> {
+        let start = *self;
+        if !self.eat("-") {
+            self.eat("+");
+        }
+        if self.take_while(|c| c.is_ascii_digit()).is_empty() {
+            *self = start;
+            return Err(self.error("expected a number"));
+        }
+
+        input::number::<T>(&start.rest[..self.offset() - start.offset()])
+    }
+
+    // whitespace separated integers, as long as the text continues with one
+    pub fn numbers<T: FromStr>(&mut self) -> Result<Vec<T>> {
+        let mut numbers: Vec<T> = Vec::new();
+        loop {
+            let before = *self;
+            self.spaces();
+
+            let mut after_sign = self.rest.trim_start_matches(['-', '+']).chars();
+            if !after_sign.next().is_some_and(|c| c.is_ascii_digit()) {
+                *self = before;
+                break;
+            }
+            numbers.push(self.integer()?);
+        }
+
+        Ok(numbers)
+    }
+
+    // one or more items with the separator between them. Whitespace around the separators is
+    // skipped, so numbers lined up in columns parse as well
+    pub fn separated<T, F>(&mut self, separator: &str, mut item: F) -> Result<Vec<T>> where F: FnMut(&mut Parser<'a>) -> Result<T> {
+        let mut items = vec![item(self)?];
+        loop {
+            let before = *self;
+            self.spaces();
+            if !self.eat(separator) {
+                *self = before;
+                break;
+            }
+            self.spaces();
+            items.push(item(self)?);
+        }
+
+        Ok(items)
+    }
+
+    // what f parses between open and close, like the ratings of a part in {}
+    pub fn bracketed<T, F>(&mut self, open: &str, close: &str, f: F) -> Result<T> where F: FnOnce(&mut Parser<'a>) -> Result<T> {
+        self.expect(open)?;
+        let value = f(self)?;
+        self.expect(close)?;
+
+        Ok(value)
+    }
+
+    // the value of a key=value pair with the given key
+    pub fn field<T, F>(&mut self, key: &str, separator: &str, value: F) -> Result<T> where F: FnOnce(&mut Parser<'a>) -> Result<T> {
+        self.expect(key)?;
+        self.expect(separator)?;
+
+        value(self)
+    }
+
+    // what f parses after a label like "Time:" and the spaces following it
+    pub fn labelled<T, F>(&mut self, label: &str, f: F) -> Result<T> where F: FnOnce(&mut Parser<'a>) -> Result<T> {
+        self.expect(label)?;
+        self.expect(":")?;
+        self.spaces();
+
+        f(self)
+    }
+
+    fn take(&mut self, len: usize) -> &'a str {
+        let (taken, rest) = self.rest.split_at(len);
+        self.rest = rest;
+
+        taken
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{all, section, Parser};
+
+    #[test]
+    fn test_combinators() {
+        let line = "Card  1: 41 -48 | 83 +86";
+        let (id, winning, drawn) = all(line, |p| {
+            p.expect("Card")?;
+            p.spaces();
+            let id = p.integer::<u32>()?;
+            p.expect(":")?;
+            let winning = p.numbers::<i32>()?;
+            p.spaces();
+            p.expect("|")?;
+
+            Ok((id, winning, p.numbers::<i32>()?))
+        }).unwrap();
+
+        assert_eq!((id, winning, drawn), (1, vec![41, -48], vec![83, 86]));
+
+        let ratings = all("{x=787,m=2655}", |p| p.bracketed("{", "}", |p| {
+            let x = p.field("x", "=", Parser::integer::<u32>)?;
+            p.expect(",")?;
+
+            Ok((x, p.field("m", "=", Parser::integer::<u32>)?))
+        })).unwrap();
+
+        assert_eq!(ratings, (787, 2655));
+        assert_eq!(all("19, 13,  30", |p| p.separated(",", Parser::integer::<i64>)).unwrap(), vec![19, 13, 30]);
+        assert_eq!(all("Time:      7  15   30", |p| p.labelled("Time", Parser::numbers::<u64>)).unwrap(), vec![7, 15, 30]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let input = "px{a<2006:qkq,rfg}\n{x=1,m=2;a=3}";
+        let line = input.lines().nth(1).unwrap();
+
+        let e = all(line, |p| p.bracketed("{", "}", |p| p.separated(",", |p| {
+            p.word()?;
+            p.expect("=")?;
+            p.integer::<u32>()
+        }))).unwrap_err();
+        assert_eq!(e.locate(input).to_string(), "2:9: expected '}' at ';a=3}'");
+
+        let e = all(line, |p| p.labelled("{x", Parser::integer::<u32>)).unwrap_err();
+        assert_eq!(e.locate(input).to_string(), "2:3: expected ':' at '=1,m=2;a=3}'");
+
+        let e = all("12 x", |p| p.integer::<u8>()).unwrap_err();
+        assert_eq!(e.to_string(), "unexpected text at 'x'");
+        let e = all("-", |p| p.integer::<i8>()).unwrap_err();
+        assert_eq!(e.to_string(), "expected a number at '-'");
+        let e = all("300", |p| p.integer::<u8>()).unwrap_err();
+        assert_eq!(e.to_string(), "expected a number at '300'");
+    }
+
+    #[test]
+    fn test_section() {
+        let lines = ["seed-to-soil map:", "50 98 2", "52 50 48"];
+
+        assert_eq!(section(&lines).unwrap(), ("seed-to-soil map", &lines[1..]));
+        assert_eq!(section(&lines[1..]).unwrap_err().to_string(), "expected a section header ending with ':' at '50 98 2'");
+    }
+}
@@ -0,0 +1,50 @@
+use std::any::Any;
+
+use crate::error::{Error, Result};
+use crate::solution::{Puzzle, Solution};
+
+// a command of a day in the runner's repl
+#[derive(Clone, Copy, Debug)]
+pub struct Command {
+    pub name: &'static str,
+    // the arguments, e.g. "<seed>"
+    pub usage: &'static str,
+    pub help: &'static str
+}
+
+impl Command {
+    // the arguments of the command, if there are exactly N of them
+    pub fn arguments<'a, const N: usize>(&self, arguments: &[&'a str]) -> Result<[&'a str; N]> {
+        arguments.try_into().map_err(|_|
+            Error::Usage(format!("usage: {} {}", self.name, self.usage))
+        )
+    }
+}
+
+// A day whose parsed input can be asked questions, e.g. where a single seed ends up
+pub trait Query: Solution {
+    const COMMANDS: &'static [Command];
+
+    // runs one of the commands with the words after it. Only called with a name from COMMANDS
+    fn query(input: &Self::Input, command: &str, arguments: &[&str]) -> Result<String>;
+}
+
+// Object safe view on a Query, like Puzzle is for Solution
+pub trait Explore: Puzzle {
+    fn commands(&self) -> &'static [Command];
+
+    fn query(&self, input: &dyn Any, command: &str, arguments: &[&str]) -> Result<String>;
+}
+
+impl<Q> Explore for Q where Q: Query + Sync, Q::Input: 'static {
+    fn commands(&self) -> &'static [Command] {
+        Q::COMMANDS
+    }
+
+    fn query(&self, input: &dyn Any, command: &str, arguments: &[&str]) -> Result<String> {
+        let input = input.downcast_ref::<Q::Input>()
+            .expect("Input was parsed by a different puzzle");
+
+        Q::query(input, command, arguments)
+    }
+}
@@ -0,0 +1,72 @@
+use std::ops::Range;
+
+// A small seeded random number generator (splitmix64). The same seed gives the same
+// numbers everywhere, so a generated input can be reproduced from its seed alone
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+
+        z ^ (z >> 31)
+    }
+
+    // a number in the range, which must not be empty
+    pub fn range(&mut self, range: Range<i64>) -> i64 {
+        assert!(range.start < range.end, "Cannot pick from the empty range {:?}", range);
+        let width = range.end.abs_diff(range.start);
+
+        range.start.wrapping_add((self.next_u64() % width) as i64)
+    }
+
+    pub fn below(&mut self, end: usize) -> usize {
+        self.range(0..end as i64) as usize
+    }
+
+    // true with the given probability
+    pub fn chance(&mut self, probability: f64) -> bool {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64 <= probability
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i + 1);
+            items.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Rng;
+
+    #[test]
+    fn test_reproducible() {
+        let numbers: Vec<i64> = (0..10).map({
+            let mut rng = Rng::new(42);
+            move |_| rng.range(-5..5)
+        }).collect();
+        let again: Vec<i64> = (0..10).map({
+            let mut rng = Rng::new(42);
+            move |_| rng.range(-5..5)
+        }).collect();
+
+        assert_eq!(numbers, again);
+        assert!(numbers.iter().all(|n| (-5..5).contains(n)));
+        assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+    }
+}
@@ -0,0 +1,164 @@
+use std::any::Any;
+use std::fmt;
+
+use num::BigInt;
+
+use crate::error::Result;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    Unsolved
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(t) => write!(f, "{}", t),
+            Answer::Unsolved => write!(f, "(unsolved)")
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Number(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64, i128);
+
+// numbers past an i128 are still right answers, they are written out as text
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        match i128::try_from(n) {
+            Ok(n) => Answer::Number(n),
+            Err(_) => Answer::Text(n.to_string())
+        }
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(n: BigInt) -> Self {
+        match i128::try_from(&n) {
+            Ok(n) => Answer::Number(n),
+            Err(_) => Answer::Text(n.to_string())
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(t: String) -> Self {
+        Answer::Text(t)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Part {
+    One,
+    Two
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn from_number(part: u8) -> Option<Self> {
+        match part {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None
+        }
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    // padded, so the part lines up in the columns of a table
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&self.number().to_string())
+    }
+}
+
+// A puzzle of one day. The input is parsed once and both parts work on the parsed
+// representation, so they can be run, tested and timed on their own.
+pub trait Solution {
+    const DAY: u8;
+    const TITLE: &'static str;
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(input: &Self::Input) -> Answer;
+}
+
+// Object safe view on a Solution, so that the runner can keep every day in one list.
+// The parsed input is passed around as Any and handed back to the day that created it.
+pub trait Puzzle: Sync {
+    fn day(&self) -> u8;
+
+    fn title(&self) -> &'static str;
+
+    fn parse_input(&self, input: &str) -> Result<Box<dyn Any>>;
+
+    fn solve(&self, input: &dyn Any, part: Part) -> Answer;
+}
+
+impl<S> Puzzle for S where S: Solution + Sync, S::Input: 'static {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn parse_input(&self, input: &str) -> Result<Box<dyn Any>> {
+        let parsed = S::parse(input).map_err(|e| e.locate(input))?;
+
+        Ok(Box::new(parsed))
+    }
+
+    fn solve(&self, input: &dyn Any, part: Part) -> Answer {
+        let input = input.downcast_ref::<S::Input>()
+            .expect("Input was parsed by a different puzzle");
+
+        match part {
+            Part::One => S::part1(input),
+            Part::Two => S::part2(input)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+
+    use super::Answer;
+
+    #[test]
+    fn test_large_numbers() {
+        assert_eq!(Answer::from(u128::MAX), Answer::Text(String::from("340282366920938463463374607431768211455")));
+        assert_eq!(Answer::from(i128::MAX as u128), Answer::Number(i128::MAX));
+
+        let large = BigInt::from(u128::MAX) * 10;
+        assert_eq!(Answer::from(large), Answer::Text(String::from("3402823669209384634633746074317682114550")));
+        assert_eq!(Answer::from(BigInt::from(-7)), Answer::Number(-7));
+    }
+}
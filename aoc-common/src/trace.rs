@@ -0,0 +1,102 @@
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+// How much the solutions explain of what they are doing, for the runner's --explain.
+// Every level includes the ones below it
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Level {
+    Quiet,
+    // one line per item of the input, e.g. the kind of every hand
+    Steps,
+    // everything which led to it, e.g. every rule which was looked at
+    Details
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(0);
+
+thread_local! {
+    // the level and the lines of the running record on this thread, see record
+    static RECORDING: RefCell<Option<(Level, Vec<String>)>> = const { RefCell::new(None) };
+}
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+// the level of the record running on this thread, or else the one set for the process
+pub fn level() -> Level {
+    if let Some(level) = RECORDING.with(|recording| recording.borrow().as_ref().map(|(level, _)| *level)) {
+        return level;
+    }
+
+    match LEVEL.load(Ordering::Relaxed) {
+        0 => Level::Quiet,
+        1 => Level::Steps,
+        _ => Level::Details
+    }
+}
+
+pub fn enabled(level: Level) -> bool {
+    level != Level::Quiet && level <= self::level()
+}
+
+// writes the message to stderr if the level is enabled. The message is only made then, so
+// the solutions do not pay for explaining when nobody is listening
+pub fn explain<F>(level: Level, message: F) where F: FnOnce() -> String {
+    if !enabled(level) {
+        return;
+    }
+
+    let message = message();
+    let recorded = RECORDING.with(|recording| match recording.borrow_mut().as_mut() {
+        Some((_, lines)) => {
+            lines.push(message.clone());
+            true
+        },
+        None => false
+    });
+    if !recorded {
+        eprintln!("{}", message);
+    }
+}
+
+// runs f at the level and returns what it explained on this thread instead of writing it, for
+// tests. The level only holds for this thread, so tests running next to it are not affected
+pub fn record<R, F>(level: Level, f: F) -> (R, Vec<String>) where F: FnOnce() -> R {
+    let previous = RECORDING.with(|recording| recording.borrow_mut().replace((level, Vec::new())));
+
+    let result = f();
+
+    let recorded = RECORDING.with(|recording| std::mem::replace(&mut *recording.borrow_mut(), previous));
+    let lines = recorded.map(|(_, lines)| lines).unwrap_or_default();
+
+    (result, lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{enabled, explain, level, record, Level};
+
+    #[test]
+    fn test_record() {
+        let (answer, lines) = record(Level::Steps, || {
+            explain(Level::Steps, || String::from("step"));
+            explain(Level::Details, || panic!("details are not wanted"));
+
+            42
+        });
+
+        assert_eq!(answer, 42);
+        assert_eq!(lines, vec!["step"]);
+    }
+
+    #[test]
+    fn test_record_stays_on_its_thread() {
+        let (other, _) = record(Level::Details, || {
+            std::thread::spawn(|| (level(), enabled(Level::Steps))).join().unwrap()
+        });
+
+        assert_eq!(other, (Level::Quiet, false));
+        assert_eq!(level(), Level::Quiet);
+    }
+}
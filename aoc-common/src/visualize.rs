@@ -0,0 +1,127 @@
+use std::any::Any;
+use std::fmt::Write;
+
+use crate::grid::Grid;
+use crate::solution::{Part, Puzzle, Solution};
+
+// A day which can draw the state its solution works on, for the runner's --visualize
+pub trait Visualize: Solution {
+    // the states the part goes through, the last one is the final state. Most parts only
+    // have that one, an empty list means there is nothing to draw for the part
+    fn frames(input: &Self::Input, part: Part) -> Vec<Frame>;
+}
+
+// Object safe view on a Visualize, like Puzzle is for Solution
+pub trait Render: Puzzle {
+    fn frames(&self, input: &dyn Any, part: Part) -> Vec<Frame>;
+}
+
+impl<V> Render for V where V: Visualize + Sync, V::Input: 'static {
+    fn frames(&self, input: &dyn Any, part: Part) -> Vec<Frame> {
+        let input = input.downcast_ref::<V::Input>()
+            .expect("Input was parsed by a different puzzle");
+
+        V::frames(input, part)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Colour {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    Grey
+}
+
+impl Colour {
+    // the ANSI code of the foreground colour
+    fn code(&self) -> u8 {
+        match self {
+            Colour::Red => 31,
+            Colour::Green => 32,
+            Colour::Yellow => 33,
+            Colour::Blue => 34,
+            Colour::Magenta => 35,
+            Colour::Cyan => 36,
+            Colour::Grey => 90
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cell {
+    pub symbol: char,
+    pub colour: Option<Colour>
+}
+
+impl Cell {
+    pub fn plain(symbol: char) -> Self {
+        Cell { symbol, colour: None }
+    }
+
+    pub fn coloured(symbol: char, colour: Colour) -> Self {
+        Cell { symbol, colour: Some(colour) }
+    }
+}
+
+// one picture of the state, with a line saying what it shows
+#[derive(Clone, Debug, PartialEq)]
+pub struct Frame {
+    pub caption: String,
+    pub cells: Grid<Cell>
+}
+
+impl Frame {
+    pub fn new(caption: impl Into<String>, cells: Grid<Cell>) -> Self {
+        Frame { caption: caption.into(), cells }
+    }
+
+    // the caption and the cells line by line. Without colour only the symbols are
+    // drawn, e.g. when the output does not go to a terminal
+    pub fn render(&self, colour: bool) -> String {
+        let mut out = format!("{}\n", self.caption);
+
+        for y in 0..self.cells.height() {
+            let mut current: Option<Colour> = None;
+            for x in 0..self.cells.width() {
+                let cell = self.cells[(x, y)];
+                if colour && cell.colour != current {
+                    match cell.colour {
+                        Some(c) => write!(out, "\x1b[{}m", c.code()).unwrap(),
+                        None => out.push_str("\x1b[0m")
+                    }
+                    current = cell.colour;
+                }
+                out.push(cell.symbol);
+            }
+            if current.is_some() {
+                out.push_str("\x1b[0m");
+            }
+            out.push('\n');
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::Grid;
+
+    use super::{Cell, Colour, Frame};
+
+    #[test]
+    fn test_render() {
+        let cells = Grid::new(3, 2, vec![
+            Cell::plain('.'), Cell::coloured('┌', Colour::Yellow), Cell::coloured('┐', Colour::Yellow),
+            Cell::plain('.'), Cell::coloured('└', Colour::Yellow), Cell::coloured('█', Colour::Green)
+        ]);
+        let frame = Frame::new("loop", cells);
+
+        assert_eq!(frame.render(false), "loop\n.┌┐\n.└█\n");
+        assert_eq!(frame.render(true), "loop\n.\x1b[33m┌┐\x1b[0m\n.\x1b[33m└\x1b[32m█\x1b[0m\n");
+    }
+}
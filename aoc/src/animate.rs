@@ -0,0 +1,34 @@
+use std::env;
+use std::io::{self, IsTerminal, Write};
+use std::thread;
+use std::time::Duration;
+
+use aoc_common::visualize::Frame;
+
+// plays the frames in place on a terminal, with colours unless NO_COLOR is set. Anywhere
+// else, e.g. when piped into a file, only the final state is written and without colours
+pub fn play(frames: &[Frame], delay: Duration) {
+    let Some(last) = frames.last() else { return };
+    let mut stdout = io::stdout().lock();
+
+    if !stdout.is_terminal() {
+        let _ = write!(stdout, "{}", last.render(false));
+        return;
+    }
+
+    let colour = env::var_os("NO_COLOR").is_none();
+    if frames.len() == 1 {
+        let _ = write!(stdout, "{}", last.render(colour));
+        return;
+    }
+
+    for (i, frame) in frames.iter().enumerate() {
+        if i > 0 {
+            thread::sleep(delay);
+        }
+
+        // clear the screen and start at the top left again
+        let _ = write!(stdout, "\x1b[2J\x1b[H{}", frame.render(colour));
+        let _ = stdout.flush();
+    }
+}
@@ -0,0 +1,177 @@
+use std::fs;
+use std::hint;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use aoc_common::{input, Error, Part, Puzzle, Result};
+use serde::{Deserialize, Serialize};
+
+// what is being timed
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2
+}
+
+impl Stage {
+    fn name(&self) -> &'static str {
+        match self {
+            Stage::Parse => "parse",
+            Stage::Part1 => "part 1",
+            Stage::Part2 => "part 2"
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Timing {
+    pub day: u8,
+    pub stage: Stage,
+    pub min_ns: u64,
+    pub median_ns: u64
+}
+
+// one call of aoc bench, as stored in the history file
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Record {
+    // seconds since the unix epoch
+    pub timestamp: u64,
+    pub runs: u32,
+    pub timings: Vec<Timing>
+}
+
+// times parsing and both parts of the puzzle, every run parses the input again
+pub fn bench_puzzle(puzzle: &dyn Puzzle, content: &str, runs: u32) -> Result<Vec<Timing>> {
+    let mut parse: Vec<Duration> = Vec::new();
+    let mut part1: Vec<Duration> = Vec::new();
+    let mut part2: Vec<Duration> = Vec::new();
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        let parsed = puzzle.parse_input(content)?;
+        parse.push(start.elapsed());
+
+        for (part, durations) in [(Part::One, &mut part1), (Part::Two, &mut part2)] {
+            let start = Instant::now();
+            hint::black_box(puzzle.solve(parsed.as_ref(), part));
+            durations.push(start.elapsed());
+        }
+    }
+
+    let timings = [(Stage::Parse, parse), (Stage::Part1, part1), (Stage::Part2, part2)].into_iter()
+        .map(|(stage, durations)| timing(puzzle.day(), stage, durations))
+        .collect();
+
+    Ok(timings)
+}
+
+fn timing(day: u8, stage: Stage, mut durations: Vec<Duration>) -> Timing {
+    durations.sort();
+    let middle = durations.len() / 2;
+    let median = if durations.len().is_multiple_of(2) {
+        (durations[middle - 1] + durations[middle]) / 2
+    } else {
+        durations[middle]
+    };
+
+    Timing { day, stage, min_ns: durations[0].as_nanos() as u64, median_ns: median.as_nanos() as u64 }
+}
+
+pub fn bench(puzzles: &[&dyn Puzzle], inputs: &Path, runs: u32) -> Result<Record> {
+    let mut timings: Vec<Timing> = Vec::new();
+
+    for &puzzle in puzzles {
+        let path = input::default_path(inputs, puzzle.day(), false);
+        if !path.exists() {
+            eprintln!("day {}: no input at {}, skipped", puzzle.day(), path.display());
+            continue;
+        }
+
+        let content = input::read_input(&path)?;
+        let puzzle_timings = bench_puzzle(puzzle, &content, runs)
+            .map_err(|e| e.in_file(&path))?;
+        timings.extend(puzzle_timings);
+    }
+
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+
+    Ok(Record { timestamp, runs, timings })
+}
+
+// all earlier records, oldest first. A missing file is an empty history
+pub fn read_history(path: &Path) -> Result<Vec<Record>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = input::read_input(path)?;
+
+    serde_json::from_str(&content).map_err(|e|
+        Error::Io { path: path.to_path_buf(), source: io::Error::from(e) }
+    )
+}
+
+pub fn write_history(path: &Path, history: &[Record]) -> Result<()> {
+    let json = serde_json::to_string_pretty(history).map_err(|e|
+        Error::Io { path: path.to_path_buf(), source: io::Error::from(e) }
+    )?;
+
+    fs::write(path, json + "\n").map_err(|source|
+        Error::Io { path: path.to_path_buf(), source }
+    )
+}
+
+// the most recent median of the same day and stage, if it was benchmarked before
+fn previous_median(history: &[Record], timing: &Timing) -> Option<u64> {
+    history.iter().rev()
+        .flat_map(|record| record.timings.iter())
+        .find(|t| t.day == timing.day && t.stage == timing.stage)
+        .map(|t| t.median_ns)
+}
+
+pub fn print_table(puzzles: &[&dyn Puzzle], record: &Record, history: &[Record]) {
+    let title_width = puzzles.iter().map(|p| p.title().len()).max().unwrap_or(0).max("puzzle".len());
+
+    println!("{} runs each", record.runs);
+    println!("{:>3}  {:<title_width$}  {:<6}  {:>10}  {:>10}  {:>10}  {:>8}",
+             "day", "puzzle", "stage", "min", "median", "previous", "change");
+    for timing in &record.timings {
+        let title = puzzles.iter().find(|p| p.day() == timing.day).map(|p| p.title()).unwrap_or("");
+        let median = Duration::from_nanos(timing.median_ns);
+
+        let (previous, change) = match previous_median(history, timing) {
+            Some(previous) => {
+                let change = (timing.median_ns as f64 - previous as f64) / previous.max(1) as f64 * 100.;
+
+                (format!("{:.2?}", Duration::from_nanos(previous)), format!("{:+.1}%", change))
+            },
+            None => (String::from("-"), String::from("-"))
+        };
+
+        println!("{:>3}  {:<title_width$}  {:<6}  {:>10}  {:>10}  {:>10}  {:>8}",
+                 timing.day, title, timing.stage.name(),
+                 format!("{:.2?}", Duration::from_nanos(timing.min_ns)), format!("{:.2?}", median),
+                 previous, change);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{timing, Stage};
+
+    #[test]
+    fn test_timing() {
+        let durations = [5, 1, 4, 2].iter().map(|&n| Duration::from_nanos(n)).collect();
+        let t = timing(3, Stage::Parse, durations);
+
+        assert_eq!((t.min_ns, t.median_ns), (1, 3));
+
+        let t = timing(3, Stage::Part1, vec![Duration::from_nanos(7)]);
+        assert_eq!((t.min_ns, t.median_ns), (7, 7));
+    }
+}
@@ -0,0 +1,78 @@
+use std::io;
+use std::path::Path;
+
+use aoc_common::{input, Error, Result};
+use serde::Deserialize;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com/2023";
+
+// settings for talking to the puzzle server, read from aoc.toml:
+//
+//   session = "53616c7465645f5f..."
+//   base_url = "http://localhost:8080/2023"
+//
+// the file holds the session cookie, so it must never be committed
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub session: Option<String>,
+    #[serde(default = "default_base_url")]
+    pub base_url: String
+}
+
+fn default_base_url() -> String {
+    String::from(DEFAULT_BASE_URL)
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { session: None, base_url: default_base_url() }
+    }
+}
+
+impl Config {
+    pub fn from(content: &str) -> std::result::Result<Self, toml::de::Error> {
+        toml::from_str(content)
+    }
+
+    // a missing file is the default configuration
+    pub fn read(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Config::default());
+        }
+
+        let content = input::read_input(path)?;
+
+        Config::from(&content).map_err(|e|
+            Error::Io { path: path.to_path_buf(), source: io::Error::new(io::ErrorKind::InvalidData, e) }
+        )
+    }
+
+    pub fn session(&self, path: &Path) -> Result<&str> {
+        self.session.as_deref().ok_or_else(||
+            Error::Usage(format!("no session token configured, add session = \"<cookie>\" to {}", path.display()))
+        )
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("{}/{}", self.base_url.trim_end_matches('/'), path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Config, DEFAULT_BASE_URL};
+
+    #[test]
+    fn test_config() {
+        let config = Config::from("session = \"abc\"\nbase_url = \"http://localhost:8080/2023/\"\n").unwrap();
+
+        assert_eq!(config.session.as_deref(), Some("abc"));
+        assert_eq!(config.url("day/5/input"), "http://localhost:8080/2023/day/5/input");
+
+        let config = Config::from("").unwrap();
+        assert_eq!(config, Config { session: None, base_url: String::from(DEFAULT_BASE_URL) });
+
+        assert!(Config::from("sesion = \"abc\"").is_err());
+    }
+}
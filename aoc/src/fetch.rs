@@ -0,0 +1,139 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_common::{input, Error, Result};
+
+use crate::config::Config;
+
+pub const USER_AGENT: &str = "github.com/AndreasBilke/aoc-2023 runner";
+
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf)
+}
+
+// the input of the day in the cache directory. A day which is already there is never
+// downloaded again, the server asks to keep the number of requests low
+pub fn fetch(config: &Config, config_path: &Path, cache: &Path, day: u8) -> Result<Fetched> {
+    let path = input::default_path(cache, day, false);
+    if path.exists() {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let session = config.session(config_path)?;
+    let url = config.url(&format!("day/{}/input", day));
+    let content = get(&url, session)?;
+
+    fs::create_dir_all(cache).map_err(|source|
+        Error::Io { path: cache.to_path_buf(), source }
+    )?;
+    // write next to the target first, so that an interrupted download is not taken for the input
+    let partial = path.with_extension("txt.part");
+    fs::write(&partial, content).map_err(|source|
+        Error::Io { path: partial.clone(), source }
+    )?;
+    fs::rename(&partial, &path).map_err(|source|
+        Error::Io { path: path.clone(), source }
+    )?;
+
+    Ok(Fetched::Downloaded(path))
+}
+
+fn get(url: &str, session: &str) -> Result<String> {
+    let response = ureq::get(url)
+        .set("Cookie", &format!("session={}", session))
+        .set("User-Agent", USER_AGENT)
+        .call()
+        .map_err(|e| Error::Http { url: url.to_string(), message: e.to_string() })?;
+
+    response.into_string().map_err(|e|
+        Error::Http { url: url.to_string(), message: e.to_string() }
+    )
+}
+
+#[cfg(test)]
+pub mod tests {
+    use std::env;
+    use std::fs;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::path::PathBuf;
+    use std::sync::mpsc::{self, Receiver};
+    use std::thread;
+
+    use super::{fetch, Fetched};
+    use crate::config::Config;
+
+    // a puzzle server on localhost, answering every request with the same body. The
+    // request line, the headers and the (form) body it got are handed out through the receiver
+    pub fn stub_server(body: &'static str) -> (String, Receiver<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut request: Vec<String> = Vec::new();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    request.push(line.trim().to_string());
+                }
+
+                let length = request.iter()
+                    .find_map(|header| header.strip_prefix("Content-Length: "))
+                    .map(|length| length.parse::<usize>().unwrap())
+                    .unwrap_or(0);
+                let mut body_received = vec![0; length];
+                reader.read_exact(&mut body_received).unwrap();
+                request.push(String::from_utf8(body_received).unwrap());
+
+                write!(stream, "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", body.len(), body).unwrap();
+                sender.send(request).unwrap();
+            }
+        });
+
+        (format!("http://{}/2023", address), receiver)
+    }
+
+    pub fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        dir
+    }
+
+    #[test]
+    fn test_fetch_once() {
+        let (base_url, requests) = stub_server("1 2 3\n");
+        let config = Config { session: Some(String::from("secret")), base_url };
+        let cache = temp_dir("fetch");
+
+        let Fetched::Downloaded(path) = fetch(&config, &cache, &cache, 5).unwrap() else { panic!("Expected a download") };
+        assert_eq!(path, cache.join("day-05.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 3\n");
+
+        let request = requests.recv().unwrap();
+        assert_eq!(request[0], "GET /2023/day/5/input HTTP/1.1");
+        assert!(request.iter().any(|h| h == "Cookie: session=secret"));
+
+        // the second time it comes from the cache
+        assert!(matches!(fetch(&config, &cache, &cache, 5).unwrap(), Fetched::Cached(_)));
+        assert!(requests.try_recv().is_err());
+
+        fs::remove_dir_all(&cache).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_without_session() {
+        let config = Config::default();
+        let cache = temp_dir("no-session");
+
+        assert!(fetch(&config, &cache.join("aoc.toml"), &cache, 5).is_err());
+    }
+}
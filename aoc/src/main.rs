@@ -0,0 +1,451 @@
+mod animate;
+mod bench;
+mod config;
+mod fetch;
+mod registry;
+mod repl;
+mod report;
+mod scaffold;
+mod submit;
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use aoc_common::input::{self, Source};
+use aoc_common::generate::{self, Stress};
+use aoc_common::trace::{self, Level};
+use aoc_common::{parallel, Answer, Error, Part, Puzzle, Result};
+use clap::{Args, Parser, Subcommand};
+
+use config::Config;
+use fetch::Fetched;
+use report::{Format, Outcome, Row};
+use submit::Ledger;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2023 puzzles")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// How many threads the solutions may use, 0 for one per core. 1 runs everything serially
+    #[arg(long, global = true, default_value_t = 0)]
+    threads: usize,
+
+    /// Let the solutions explain their steps on stderr, as far as they can
+    #[arg(long, global = true)]
+    explain: bool,
+
+    /// Explain in more detail, e.g. every rule which was used instead of only the result
+    #[arg(short, long, global = true, action = clap::ArgAction::Count, requires = "explain")]
+    verbose: u8
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single puzzle, or every puzzle with --all
+    Run(RunArgs),
+    /// Time parsing and both parts of every puzzle over several runs
+    Bench(BenchArgs),
+    /// Create the crate of a new day and add it to the runner
+    New(NewArgs),
+    /// Download the input of a day into the input directory, unless it is already there
+    Fetch(FetchArgs),
+    /// Solve a part and send the answer, unless the ledger shows it cannot be right
+    Submit(SubmitArgs),
+    /// Print a random input for a day
+    Generate(GenerateArgs),
+    /// Check the solutions on random inputs against their slow reference solutions
+    Stress(StressArgs),
+    /// Parse the input of a day once and ask questions about it, e.g. where a seed ends up
+    Repl(ReplArgs)
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Run every registered puzzle on its input from the input directory
+    #[arg(long, conflicts_with_all = ["day", "part", "input"])]
+    all: bool,
+
+    /// Directory holding one day-NN.txt input per day, used when no input is given
+    #[arg(long, default_value = "inputs")]
+    inputs: PathBuf,
+
+    /// Read day-NN.example.txt from the input directory instead of day-NN.txt
+    #[arg(long)]
+    example: bool,
+
+    /// How the results are printed
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Draw the state of the part before printing its answer, animated if it has several steps
+    #[arg(long, conflicts_with = "all")]
+    visualize: bool,
+
+    /// Milliseconds between the steps of an animation
+    #[arg(long, default_value_t = 100, requires = "visualize")]
+    delay: u64,
+
+    /// Write a picture of the part to this file, an .svg or a .ppm depending on the day
+    #[arg(long, conflicts_with = "all")]
+    image: Option<PathBuf>,
+
+    #[arg(required_unless_present = "all")]
+    day: Option<u8>,
+
+    #[arg(required_unless_present = "all", value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Input file, - for stdin. Defaults to the day's file in the input directory
+    input: Option<PathBuf>
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Only benchmark these days, all of them if none is given
+    days: Vec<u8>,
+
+    /// How often every puzzle is run, min and median are taken over all runs
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+
+    /// Directory holding one day-NN.txt input per day
+    #[arg(long, default_value = "inputs")]
+    inputs: PathBuf,
+
+    /// JSON file every benchmark is appended to, and compared against
+    #[arg(long, default_value = "bench-history.json")]
+    history: PathBuf,
+
+    /// Only compare against the history, do not add this run to it
+    #[arg(long)]
+    no_save: bool
+}
+
+#[derive(Args)]
+struct NewArgs {
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Directory the inputs are cached in
+    #[arg(long, default_value = "inputs")]
+    inputs: PathBuf,
+
+    /// Config file with the session token and the server URL
+    #[arg(long, default_value = "aoc.toml")]
+    config: PathBuf
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Input file, - for stdin. Defaults to the day's file in the input directory
+    input: Option<PathBuf>,
+
+    /// Directory holding one day-NN.txt input per day, used when no input is given
+    #[arg(long, default_value = "inputs")]
+    inputs: PathBuf,
+
+    /// Config file with the session token and the server URL
+    #[arg(long, default_value = "aoc.toml")]
+    config: PathBuf,
+
+    /// JSON file with every submitted answer and what the server said about it
+    #[arg(long, default_value = "submissions.json")]
+    ledger: PathBuf
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    day: u8,
+
+    /// The same seed always gives the same input
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// How big the input gets, what exactly it means depends on the day
+    #[arg(long, default_value_t = 10)]
+    size: usize
+}
+
+#[derive(Args)]
+struct StressArgs {
+    /// Only check these days, all days with a generator if none is given
+    days: Vec<u8>,
+
+    /// Seed of the first input, the following inputs use the next seeds
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// How many inputs are generated per day
+    #[arg(long, default_value_t = 100)]
+    count: u64,
+
+    /// How big the inputs get, what exactly it means depends on the day
+    #[arg(long, default_value_t = 10)]
+    size: usize
+}
+
+#[derive(Args)]
+struct ReplArgs {
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Input file, - for stdin. Defaults to the day's file in the input directory
+    input: Option<PathBuf>,
+
+    /// Directory holding one day-NN.txt input per day, used when no input is given
+    #[arg(long, default_value = "inputs")]
+    inputs: PathBuf,
+
+    /// Read day-NN.example.txt from the input directory instead of day-NN.txt
+    #[arg(long)]
+    example: bool
+}
+
+fn main() {
+    let cli = Cli::parse();
+    parallel::set_threads(cli.threads);
+    trace::set_level(match (cli.explain, cli.verbose) {
+        (false, _) => Level::Quiet,
+        (true, 0) => Level::Steps,
+        (true, _) => Level::Details
+    });
+
+    let result = match cli.command {
+        Command::Run(args) => run(&args),
+        Command::Bench(args) => run_bench(&args),
+        Command::New(args) => new_day(&args),
+        Command::Fetch(args) => fetch_input(&args),
+        Command::Submit(args) => submit_answer(&args),
+        Command::Generate(args) => generate_input(&args),
+        Command::Stress(args) => stress(&args),
+        Command::Repl(args) => repl(&args)
+    };
+
+    if let Err(e) = result {
+        e.exit();
+    }
+}
+
+fn run(args: &RunArgs) -> Result<()> {
+    let rows = if args.all {
+        run_all(&args.inputs, args.example)?
+    } else {
+        let day = args.day.unwrap();
+        let part = Part::from_number(args.part.unwrap()).unwrap();
+        let puzzle = registry::get(day)?;
+
+        let source = Source::resolve(args.input.as_deref(), &args.inputs, day, args.example);
+        let content = source.read()?;
+        if args.visualize {
+            visualize(day, part, &content, &source, Duration::from_millis(args.delay))?;
+        }
+        if let Some(path) = &args.image {
+            write_image(day, part, &content, &source, path)?;
+        }
+
+        run_puzzle(puzzle, &[part], &content, source.path())?
+    };
+
+    // a reader which stops early, like head, has seen all it wants
+    match report::print(&rows, args.format) {
+        Err(e) if e.kind() != io::ErrorKind::BrokenPipe => Err(Error::Io { path: PathBuf::from("<stdout>"), source: e }),
+        _ => Ok(())
+    }
+}
+
+fn run_bench(args: &BenchArgs) -> Result<()> {
+    let puzzles: Vec<&dyn Puzzle> = if args.days.is_empty() {
+        registry::PUZZLES.to_vec()
+    } else {
+        args.days.iter().map(|&day| registry::get(day)).collect::<Result<_>>()?
+    };
+
+    let mut history = bench::read_history(&args.history)?;
+    let record = bench::bench(&puzzles, &args.inputs, args.runs)?;
+    bench::print_table(&puzzles, &record, &history);
+
+    if !args.no_save {
+        history.push(record);
+        bench::write_history(&args.history, &history)?;
+    }
+
+    Ok(())
+}
+
+fn new_day(args: &NewArgs) -> Result<()> {
+    let dir = scaffold::new_day(args.day)?;
+    println!("Created {}, add the examples to its fixtures and rebuild to run it", dir.display());
+
+    Ok(())
+}
+
+fn fetch_input(args: &FetchArgs) -> Result<()> {
+    let config = Config::read(&args.config)?;
+
+    match fetch::fetch(&config, &args.config, &args.inputs, args.day)? {
+        Fetched::Cached(path) => println!("Day {} is already cached in {}", args.day, path.display()),
+        Fetched::Downloaded(path) => println!("Downloaded day {} to {}", args.day, path.display())
+    }
+
+    Ok(())
+}
+
+fn submit_answer(args: &SubmitArgs) -> Result<()> {
+    let puzzle = registry::get(args.day)?;
+    let part = Part::from_number(args.part).unwrap();
+    let source = Source::resolve(args.input.as_deref(), &args.inputs, args.day, false);
+
+    let content = source.read()?;
+    let parsed = puzzle.parse_input(&content).map_err(|e| e.in_file(source.path()))?;
+    let answer = match puzzle.solve(parsed.as_ref(), part) {
+        Answer::Unsolved => return Err(Error::Usage(format!("day {} part {} is not solved yet", args.day, part))),
+        answer => answer.to_string()
+    };
+
+    let mut ledger = Ledger::read(&args.ledger)?;
+    if let Some(reason) = ledger.rejection(args.day, part, &answer) {
+        return Err(Error::Usage(format!("not submitting {} for day {} part {}: {}", answer, args.day, part, reason)));
+    }
+
+    let config = Config::read(&args.config)?;
+    let verdict = submit::post(&config, &args.config, args.day, part, &answer)?;
+    ledger.record(args.day, part, &answer, verdict);
+    ledger.write(&args.ledger)?;
+
+    println!("Day {} part {}: {} is {}", args.day, part, answer, verdict);
+
+    Ok(())
+}
+
+fn generate_input(args: &GenerateArgs) -> Result<()> {
+    let generator = registry::generator(args.day)?;
+    print!("{}", generator.generate(args.seed, args.size));
+
+    Ok(())
+}
+
+fn stress(args: &StressArgs) -> Result<()> {
+    let generators: Vec<&dyn Stress> = if args.days.is_empty() {
+        registry::GENERATORS.to_vec()
+    } else {
+        args.days.iter().map(|&day| registry::generator(day)).collect::<Result<_>>()?
+    };
+
+    let mut failures = 0;
+    for generator in generators {
+        let mut day_failures = 0;
+        for seed in args.seed..args.seed + args.count {
+            let mismatches = generate::compare(generator, seed, args.size)
+                .map_err(|e| e.in_file(Path::new(&format!("generated input, seed {}", seed))))?;
+
+            for m in mismatches.iter() {
+                println!("day {} part {}: expected {}, got {} (aoc generate {} --seed {} --size {})",
+                         m.day, m.part, m.expected, m.actual, m.day, m.seed, args.size);
+            }
+            day_failures += mismatches.len();
+        }
+
+        if day_failures == 0 {
+            println!("day {}: {} inputs, no differences", generator.day(), args.count);
+        }
+        failures += day_failures;
+    }
+
+    if failures > 0 {
+        return Err(Error::Usage(format!("{} answer(s) differ from the reference", failures)));
+    }
+
+    Ok(())
+}
+
+fn repl(args: &ReplArgs) -> Result<()> {
+    let explorer = registry::explorer(args.day)?;
+    let source = Source::resolve(args.input.as_deref(), &args.inputs, args.day, args.example);
+    let content = source.read()?;
+
+    repl::run(explorer, &content, source.path())
+}
+
+fn visualize(day: u8, part: Part, content: &str, source: &Source, delay: Duration) -> Result<()> {
+    let visualizer = registry::visualizer(day)?;
+    let parsed = visualizer.parse_input(content).map_err(|e| e.in_file(source.path()))?;
+
+    let frames = visualizer.frames(parsed.as_ref(), part);
+    if frames.is_empty() {
+        return Err(Error::Usage(format!("there is nothing to draw for day {} part {}", day, part)));
+    }
+    animate::play(&frames, delay);
+
+    Ok(())
+}
+
+fn write_image(day: u8, part: Part, content: &str, source: &Source, path: &Path) -> Result<()> {
+    let exporter = registry::picture(day)?;
+    let parsed = exporter.parse_input(content).map_err(|e| e.in_file(source.path()))?;
+
+    let Some(image) = exporter.draw(parsed.as_ref(), part) else {
+        return Err(Error::Usage(format!("there is nothing to draw for day {} part {}", day, part)));
+    };
+    if path.extension().is_none_or(|e| e != image.extension()) {
+        return Err(Error::Usage(format!("day {} draws a .{} picture, {} has a different extension", day, image.extension(), path.display())));
+    }
+
+    fs::write(path, image.bytes()).map_err(|source|
+        Error::Io { path: path.to_path_buf(), source }
+    )
+}
+
+fn run_all(inputs: &Path, example: bool) -> Result<Vec<Row>> {
+    let mut rows: Vec<Row> = Vec::new();
+
+    for &puzzle in registry::PUZZLES {
+        let path = input::default_path(inputs, puzzle.day(), example);
+        if !path.exists() {
+            for part in Part::BOTH {
+                rows.push(Row { day: puzzle.day(), part, title: puzzle.title(), outcome: Outcome::MissingInput });
+            }
+            continue;
+        }
+
+        let content = Source::File(path.clone()).read()?;
+        rows.extend(run_puzzle(puzzle, &Part::BOTH, &content, &path)?);
+    }
+
+    Ok(rows)
+}
+
+// parses the input once and solves the given parts on it. Parsing is shared by all
+// parts, so its time is counted with the first one
+fn run_puzzle(puzzle: &dyn Puzzle, parts: &[Part], content: &str, path: &Path) -> Result<Vec<Row>> {
+    let start = Instant::now();
+    let parsed = puzzle.parse_input(content).map_err(|e| e.in_file(path))?;
+    let mut parse_time = start.elapsed();
+
+    let mut rows: Vec<Row> = Vec::new();
+    for &part in parts {
+        let start = Instant::now();
+        let answer = puzzle.solve(parsed.as_ref(), part);
+        let elapsed = start.elapsed() + parse_time;
+        parse_time = Duration::ZERO;
+
+        rows.push(Row { day: puzzle.day(), part, title: puzzle.title(), outcome: Outcome::Solved { answer, elapsed } });
+    }
+
+    Ok(rows)
+}
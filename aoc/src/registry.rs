@@ -0,0 +1,222 @@
+use aoc_common::generate::Stress;
+use aoc_common::image::Export;
+use aoc_common::query::Explore;
+use aoc_common::visualize::Render;
+use aoc_common::{Error, Puzzle, Result};
+
+// every puzzle the runner knows about, ordered by day
+pub const PUZZLES: &[&dyn Puzzle] = &[
+    &day_1::Day1,
+    &day_2::Day2,
+    &day_3::Day3,
+    &day_4::Day4,
+    &day_5::Day5,
+    &day_6::Day6,
+    &day_7::Day7,
+    &day_8::Day8,
+    &day_9::Day9,
+    &day_10::Day10,
+    &day_11::Day11,
+    &day_12::Day12,
+    &day_13::Day13,
+    &day_14::Day14,
+    &day_15::Day15,
+    &day_16::Day16,
+    &day_18::Day18,
+    &day_19::Day19,
+    &day_21::Day21,
+    &day_23::Day23,
+    &day_24::Day24,
+];
+
+// the days which can generate random inputs and check them against a reference
+pub const GENERATORS: &[&dyn Stress] = &[
+    &day_5::Day5,
+    &day_10::Day10,
+    &day_13::Day13,
+    &day_19::Day19,
+    &day_24::Day24,
+];
+
+// the days which can draw their state for --visualize
+pub const VISUALIZERS: &[&dyn Render] = &[
+    &day_10::Day10,
+    &day_14::Day14,
+    &day_16::Day16,
+    &day_21::Day21,
+    &day_23::Day23,
+];
+
+// the days which can draw a picture for --image
+pub const PICTURES: &[&dyn Export] = &[
+    &day_10::Day10,
+    &day_16::Day16,
+    &day_18::Day18,
+    &day_24::Day24,
+];
+
+// the days which answer questions about their input in the repl
+pub const QUERIES: &[&dyn Explore] = &[
+    &day_5::Day5,
+    &day_16::Day16,
+    &day_19::Day19,
+    &day_21::Day21,
+];
+
+pub fn find(day: u8) -> Option<&'static dyn Puzzle> {
+    PUZZLES.iter().find(|puzzle| puzzle.day() == day).copied()
+}
+
+// like find, with an error for a day without a puzzle
+pub fn get(day: u8) -> Result<&'static dyn Puzzle> {
+    find(day).ok_or_else(|| Error::Usage(format!("there is no puzzle for day {}", day)))
+}
+
+pub fn generator(day: u8) -> Result<&'static dyn Stress> {
+    GENERATORS.iter().find(|g| g.day() == day).copied().ok_or_else(||
+        Error::Usage(format!("there is no input generator for day {}", day))
+    )
+}
+
+pub fn explorer(day: u8) -> Result<&'static dyn Explore> {
+    QUERIES.iter().find(|q| q.day() == day).copied().ok_or_else(||
+        Error::Usage(format!("there is no repl for day {}", day))
+    )
+}
+
+pub fn picture(day: u8) -> Result<&'static dyn Export> {
+    PICTURES.iter().find(|p| p.day() == day).copied().ok_or_else(||
+        Error::Usage(format!("there is no picture for day {}", day))
+    )
+}
+
+pub fn visualizer(day: u8) -> Result<&'static dyn Render> {
+    VISUALIZERS.iter().find(|v| v.day() == day).copied().ok_or_else(||
+        Error::Usage(format!("there is no visualization for day {}", day))
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+    use std::sync::mpsc::{self, RecvTimeoutError};
+    use std::thread;
+    use std::time::Duration;
+
+    use aoc_common::Part;
+
+    use proptest::prelude::*;
+
+    use super::PUZZLES;
+
+    // the examples of every day, the mutations start from them to get past the first line
+    fn examples() -> Vec<(usize, String)> {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        let mut examples = Vec::new();
+        for (i, puzzle) in PUZZLES.iter().enumerate() {
+            let fixtures = root.join(format!("day-{}", puzzle.day())).join("fixtures");
+            for entry in fs::read_dir(fixtures).unwrap() {
+                let path = entry.unwrap().path();
+                if path.file_name().unwrap() != "answers.txt" {
+                    examples.push((i, fs::read_to_string(path).unwrap()));
+                }
+            }
+        }
+
+        examples
+    }
+
+    // removing, replacing or inserting a char somewhere, mostly ones the inputs use anyway
+    fn edit() -> impl Strategy<Value = (prop::sample::Index, u8, char)> {
+        let chars = vec!['0', '9', '-', ' ', '\n', ',', ':', '=', '{', '}', '@', '#', '.', 'a', 'x', 'A', '|', 'é'];
+
+        (any::<prop::sample::Index>(), 0..3u8, prop::sample::select(chars))
+    }
+
+    // numbers at the edges of the integer types the puzzles use, and far past them
+    fn large_number() -> impl Strategy<Value = &'static str> {
+        prop::sample::select(vec![
+            "4294967295", "4294967296", "2147483648", "9223372036854775807", "18446744073709551615",
+            "170141183460469231731687303715884105727", "340282366920938463463374607431768211456"
+        ])
+    }
+
+    // far longer than any part takes on an example, even in a debug build
+    const TIME_LIMIT: Duration = Duration::from_secs(10);
+
+    // the parts run on everything which parses, in a thread of their own so that a part
+    // which never ends shows up as well
+    fn check_puzzle(i: usize, input: String) -> Result<(), TestCaseError> {
+        let puzzle = PUZZLES[i];
+
+        let (sender, receiver) = mpsc::channel();
+        let text = input.clone();
+        thread::spawn(move || {
+            if let Ok(parsed) = puzzle.parse_input(&text) {
+                for part in Part::BOTH {
+                    puzzle.solve(parsed.as_ref(), part);
+                }
+            }
+            sender.send(()).unwrap();
+        });
+        match receiver.recv_timeout(TIME_LIMIT) {
+            Ok(()) => {},
+            Err(RecvTimeoutError::Disconnected) => prop_assert!(false, "day {} panicked on {:?}", puzzle.day(), input),
+            Err(RecvTimeoutError::Timeout) => prop_assert!(false, "day {} did not finish on {:?}", puzzle.day(), input)
+        }
+
+        Ok(())
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(4000))]
+        #[test]
+        fn test_puzzles_do_not_panic(example in any::<prop::sample::Index>(), edits in prop::collection::vec(edit(), 1..6)) {
+            let examples = examples();
+            let (i, text) = &examples[example.index(examples.len())];
+            let mut chars: Vec<char> = text.chars().collect();
+            for (at, op, c) in edits {
+                let at = at.index(chars.len() + 1);
+                match op {
+                    0 if at < chars.len() => { chars.remove(at); },
+                    1 if at < chars.len() => chars[at] = c,
+                    _ => chars.insert(at, c)
+                }
+            }
+
+            check_puzzle(*i, chars.into_iter().collect())?;
+        }
+
+        // the examples keep their shape, so they still parse with some of their numbers
+        // replaced by large ones
+        #[test]
+        fn test_large_numbers_do_not_panic(example in any::<prop::sample::Index>(), numbers in prop::collection::vec((any::<prop::sample::Index>(), large_number()), 1..4)) {
+            let examples: Vec<(usize, String)> = examples().into_iter().filter(|(_, text)| text.contains(|c: char| c.is_ascii_digit())).collect();
+            let (i, text) = &examples[example.index(examples.len())];
+
+            // the byte ranges of the numbers in the example
+            let mut runs: Vec<(usize, usize)> = Vec::new();
+            for (at, c) in text.char_indices() {
+                if !c.is_ascii_digit() {
+                    continue;
+                }
+                match runs.last_mut() {
+                    Some((_, end)) if *end == at => *end = at + 1,
+                    _ => runs.push((at, at + 1))
+                }
+            }
+
+            let mut replacements: Vec<((usize, usize), &str)> = numbers.iter().map(|(at, number)| (runs[at.index(runs.len())], *number)).collect();
+            replacements.sort();
+            replacements.dedup_by_key(|(run, _)| *run);
+
+            let mut input = text.clone();
+            for ((start, end), number) in replacements.into_iter().rev() {
+                input.replace_range(start..end, number);
+            }
+
+            check_puzzle(*i, input)?;
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use std::any::Any;
+use std::path::Path;
+
+use aoc_common::query::Explore;
+use aoc_common::{Answer, Error, Part, Result};
+use rustyline::completion::Completer;
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::history::DefaultHistory;
+use rustyline::validate::Validator;
+use rustyline::{Context, Editor, Helper};
+
+// the lines of earlier sessions, in the directory the runner is started from
+const HISTORY: &str = ".aoc-history";
+
+// the commands the repl knows for every day, next to the ones of the day
+const BUILTINS: [(&str, &str, &str); 3] = [
+    ("help", "", "this list"),
+    ("part", "<1|2>", "the answer of a part"),
+    ("quit", "", "leave the repl, like exit or ctrl-d")
+];
+
+enum Reply {
+    Output(String),
+    Quit
+}
+
+// completes the command names, the arguments are up to the user
+struct Commands {
+    names: Vec<&'static str>
+}
+
+impl Completer for Commands {
+    type Candidate = String;
+
+    fn complete(&self, line: &str, pos: usize, _: &Context<'_>) -> rustyline::Result<(usize, Vec<String>)> {
+        let word = &line[..pos];
+        if word.trim_start().contains(char::is_whitespace) {
+            return Ok((pos, Vec::new()));
+        }
+
+        let start = word.len() - word.trim_start().len();
+        let candidates = self.names.iter()
+            .filter(|name| name.starts_with(&word[start..]))
+            .map(|name| name.to_string())
+            .collect();
+
+        Ok((start, candidates))
+    }
+}
+
+impl Hinter for Commands {
+    type Hint = String;
+}
+
+impl Highlighter for Commands {}
+
+impl Validator for Commands {}
+
+impl Helper for Commands {}
+
+// parses the input once and answers the commands on it until the user quits
+pub fn run(explorer: &dyn Explore, content: &str, path: &Path) -> Result<()> {
+    let parsed = explorer.parse_input(content).map_err(|e| e.in_file(path))?;
+
+    let mut names: Vec<&str> = explorer.commands().iter().map(|c| c.name).collect();
+    names.extend(BUILTINS.iter().map(|&(name, _, _)| name));
+    names.push("exit");
+
+    let mut editor: Editor<Commands, DefaultHistory> = Editor::new().map_err(readline_error)?;
+    editor.set_helper(Some(Commands { names }));
+    // there is no history before the first session
+    let _ = editor.load_history(HISTORY);
+
+    println!("Day {}: {}, {} loaded. Type help for the commands", explorer.day(), explorer.title(), path.display());
+    let prompt = format!("day {}> ", explorer.day());
+    loop {
+        let line = match editor.readline(&prompt) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => break,
+            Err(e) => return Err(readline_error(e))
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        editor.add_history_entry(line.as_str()).map_err(readline_error)?;
+
+        match execute(explorer, parsed.as_ref(), &line) {
+            Ok(Reply::Output(output)) => println!("{}", output),
+            Ok(Reply::Quit) => break,
+            Err(e) => eprintln!("error: {}", e.locate(&line))
+        }
+    }
+
+    if let Err(e) = editor.save_history(HISTORY) {
+        eprintln!("could not save the history to {}: {}", HISTORY, e);
+    }
+
+    Ok(())
+}
+
+fn execute(explorer: &dyn Explore, parsed: &dyn Any, line: &str) -> Result<Reply> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let (&command, arguments) = words.split_first().unwrap();
+
+    match command {
+        "help" => Ok(Reply::Output(help(explorer))),
+        "quit" | "exit" => Ok(Reply::Quit),
+        "part" => {
+            let part = match arguments {
+                [number] => number.parse::<u8>().ok().and_then(Part::from_number),
+                _ => None
+            };
+            let Some(part) = part else {
+                return Err(Error::Usage(String::from("usage: part <1|2>")));
+            };
+
+            match explorer.solve(parsed, part) {
+                Answer::Unsolved => Err(Error::Usage(format!("day {} part {} is not solved yet", explorer.day(), part))),
+                answer => Ok(Reply::Output(answer.to_string()))
+            }
+        },
+        _ if explorer.commands().iter().any(|c| c.name == command) => {
+            explorer.query(parsed, command, arguments).map(Reply::Output)
+        },
+        _ => Err(Error::Usage(format!("there is no command {}, type help for the commands", command)))
+    }
+}
+
+fn help(explorer: &dyn Explore) -> String {
+    let mut lines: Vec<(String, &str)> = explorer.commands().iter()
+        .map(|c| (format!("{} {}", c.name, c.usage).trim_end().to_string(), c.help))
+        .collect();
+    lines.extend(BUILTINS.iter().map(|&(name, usage, help)| (format!("{} {}", name, usage).trim_end().to_string(), help)));
+
+    let width = lines.iter().map(|(usage, _)| usage.len()).max().unwrap_or(0);
+    let lines: Vec<String> = lines.iter()
+        .map(|(usage, help)| format!("  {:width$}  {}", usage, help, width = width))
+        .collect();
+
+    lines.join("\n")
+}
+
+fn readline_error(e: ReadlineError) -> Error {
+    Error::Usage(format!("the repl failed: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use crate::registry;
+
+    use super::{execute, Reply};
+
+    fn reply(day: u8, line: &str) -> String {
+        let explorer = registry::explorer(day).unwrap();
+        let example = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+            .join(format!("day-{}", day)).join("fixtures").join("example.txt");
+        let parsed = explorer.parse_input(&fs::read_to_string(example).unwrap()).unwrap();
+
+        match execute(explorer, parsed.as_ref(), line) {
+            Ok(Reply::Output(output)) => output,
+            Ok(Reply::Quit) => String::from("quit"),
+            Err(e) => format!("error: {}", e.locate(line))
+        }
+    }
+
+    #[test]
+    fn test_execute() {
+        assert_eq!(reply(5, "convert 79"), "seed 79 -> soil 81 -> fertilizer 81 -> water 81 -> light 74 -> temperature 78 -> humidity 78 -> location 82");
+        assert_eq!(reply(5, "part 1"), "35");
+        assert_eq!(reply(5, "convert"), "error: usage: convert <seed>");
+        assert_eq!(reply(5, "bogus"), "error: there is no command bogus, type help for the commands");
+        assert_eq!(reply(19, "eval {x=787,m=2655,a=1222,s=2876}"), "in -> qqz -> qs -> lnx -> A\naccepted, rating 7540");
+        assert_eq!(reply(16, "beam 0 0 right"), "46 tiles are energized");
+        assert_eq!(reply(21, "steps 6"), "16 plots can be reached in 6 steps");
+        assert_eq!(reply(21, "quit"), "quit");
+    }
+}
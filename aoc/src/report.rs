@@ -0,0 +1,157 @@
+use std::io::{self, Write};
+use std::time::Duration;
+
+use aoc_common::{Answer, Part};
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::Value;
+
+pub struct Row {
+    pub day: u8,
+    pub part: Part,
+    pub title: &'static str,
+    pub outcome: Outcome
+}
+
+pub enum Outcome {
+    Solved { answer: Answer, elapsed: Duration },
+    MissingInput
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum Format {
+    /// A table for humans
+    Text,
+    /// A JSON array with one record per day and part
+    Json,
+    /// Tab separated values with a header line
+    Tsv
+}
+
+// one row in the machine readable formats. Missing inputs have neither answer nor time
+#[derive(Serialize)]
+struct Record {
+    day: u8,
+    part: u8,
+    answer: Value,
+    elapsed_ns: Option<u64>
+}
+
+impl Record {
+    fn from(row: &Row) -> Self {
+        let (answer, elapsed_ns) = match &row.outcome {
+            Outcome::Solved { answer, elapsed } => (answer_value(answer), Some(elapsed.as_nanos() as u64)),
+            Outcome::MissingInput => (Value::Null, None)
+        };
+
+        Record { day: row.day, part: row.part.number(), answer, elapsed_ns }
+    }
+}
+
+// numbers stay numbers as long as every JSON reader can handle them
+fn answer_value(answer: &Answer) -> Value {
+    match answer {
+        Answer::Number(n) => i64::try_from(*n).map(Value::from).unwrap_or_else(|_| Value::from(n.to_string())),
+        Answer::Text(t) => Value::from(t.as_str()),
+        Answer::Unsolved => Value::Null
+    }
+}
+
+// the rows on stdout. A reader which stops early, like head, makes this a BrokenPipe error
+pub fn print(rows: &[Row], format: Format) -> io::Result<()> {
+    let mut out = io::stdout().lock();
+
+    match format {
+        Format::Text => print_table(&mut out, rows),
+        Format::Json => print_json(&mut out, rows),
+        Format::Tsv => print_tsv(&mut out, rows)
+    }
+}
+
+fn print_json(out: &mut impl Write, rows: &[Row]) -> io::Result<()> {
+    let records: Vec<Record> = rows.iter().map(Record::from).collect();
+
+    writeln!(out, "{}", serde_json::to_string_pretty(&records).expect("Records are always valid JSON"))
+}
+
+fn print_tsv(out: &mut impl Write, rows: &[Row]) -> io::Result<()> {
+    writeln!(out, "day\tpart\tanswer\telapsed_ns")?;
+    for row in rows {
+        let (answer, elapsed) = match &row.outcome {
+            Outcome::Solved { answer: Answer::Unsolved, elapsed } => (String::new(), elapsed.as_nanos().to_string()),
+            Outcome::Solved { answer, elapsed } => (answer.to_string(), elapsed.as_nanos().to_string()),
+            Outcome::MissingInput => (String::new(), String::new())
+        };
+
+        writeln!(out, "{}\t{}\t{}\t{}", row.day, row.part, answer, elapsed)?;
+    }
+
+    Ok(())
+}
+
+fn print_table(out: &mut impl Write, rows: &[Row]) -> io::Result<()> {
+    let answer_width = rows.iter().map(|row| match &row.outcome {
+        Outcome::Solved { answer, .. } => answer.to_string().len(),
+        Outcome::MissingInput => 0
+    }).max().unwrap_or(0).max("answer".len());
+    let title_width = rows.iter().map(|row| row.title.len()).max().unwrap_or(0).max("puzzle".len());
+
+    writeln!(out, "{:>3} {:>4}  {:<title_width$}  {:<answer_width$}  {:>10}", "day", "part", "puzzle", "answer", "time")?;
+    let mut total = Duration::ZERO;
+    for row in rows {
+        let (answer, elapsed) = match &row.outcome {
+            Outcome::Solved { answer, elapsed } => {
+                total += *elapsed;
+
+                (answer.to_string(), format!("{:.2?}", elapsed))
+            },
+            Outcome::MissingInput => (String::from("(no input)"), String::from("-"))
+        };
+
+        writeln!(out, "{:>3} {:>4}  {:<title_width$}  {:<answer_width$}  {:>10}", row.day, row.part, row.title, answer, elapsed)?;
+    }
+
+    if rows.len() > 1 {
+        writeln!(out, "{:>3} {:>4}  {:<title_width$}  {:<answer_width$}  {:>10}", "", "", "total", "", format!("{:.2?}", total))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use aoc_common::{Answer, Part};
+
+    use super::{print_tsv, Outcome, Record, Row};
+
+    #[test]
+    fn test_record() {
+        let solved = Row { day: 7, part: Part::Two, title: "Camel Cards", outcome: Outcome::Solved {
+            answer: Answer::Number(5905), elapsed: Duration::from_nanos(1200)
+        } };
+        let missing = Row { day: 8, part: Part::One, title: "Haunted Wasteland", outcome: Outcome::MissingInput };
+
+        assert_eq!(serde_json::to_string(&Record::from(&solved)).unwrap(),
+                   r#"{"day":7,"part":2,"answer":5905,"elapsed_ns":1200}"#);
+        assert_eq!(serde_json::to_string(&Record::from(&missing)).unwrap(),
+                   r#"{"day":8,"part":1,"answer":null,"elapsed_ns":null}"#);
+    }
+
+    #[test]
+    fn test_part_column() {
+        // the part stands under the right end of its header
+        assert_eq!(format!("{:>4}", Part::One), "   1");
+        assert_eq!(format!("{:<2}|", Part::Two), "2 |");
+    }
+
+    #[test]
+    fn test_tsv() {
+        let rows = [Row { day: 8, part: Part::One, title: "Haunted Wasteland", outcome: Outcome::MissingInput }];
+        let mut out: Vec<u8> = Vec::new();
+        print_tsv(&mut out, &rows).unwrap();
+
+        assert_eq!(String::from_utf8(out).unwrap(), "day\tpart\tanswer\telapsed_ns\n8\t1\t\t\n");
+    }
+}
@@ -0,0 +1,275 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use aoc_common::{input, Error, Result};
+
+const CARGO_TOML: &str = r#"[package]
+name = "day-$DAY"
+version.workspace = true
+edition.workspace = true
+
+[dependencies]
+aoc-common.workspace = true
+
+[lints]
+workspace = true
+"#;
+
+const LIB_RS: &str = r#"use aoc_common::{input, Answer, Result, Solution};
+
+pub struct Day$DAY;
+
+impl Solution for Day$DAY {
+    const DAY: u8 = $DAY;
+    const TITLE: &'static str = "TODO";
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines = input::lines(input);
+
+        Ok(lines.iter().map(|line| line.to_string()).collect())
+    }
+
+    fn part1(_lines: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part2(_lines: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::examples;
+
+    use super::Day$DAY;
+
+    #[test]
+    fn test_examples() {
+        examples::check(&Day$DAY, env!("CARGO_MANIFEST_DIR"));
+    }
+}
+"#;
+
+const ANSWERS: &str = "# example file, part, expected answer
+# example.txt 1 <answer from the puzzle text>
+";
+
+// the directory of the workspace manifest, searched upwards from the current directory
+fn workspace_root() -> Result<PathBuf> {
+    let current = env::current_dir().map_err(|source|
+        Error::Io { path: PathBuf::from("."), source }
+    )?;
+
+    current.ancestors()
+        .find(|dir| {
+            let manifest = dir.join("Cargo.toml");
+            manifest.exists() && input::read_input(&manifest).map(|m| m.contains("[workspace]")).unwrap_or(false)
+        })
+        .map(|dir| dir.to_path_buf())
+        .ok_or_else(|| Error::Usage(String::from("aoc new has to be run inside of the workspace")))
+}
+
+fn write(path: &Path, content: &str) -> Result<()> {
+    fs::write(path, content).map_err(|source|
+        Error::Io { path: path.to_path_buf(), source }
+    )
+}
+
+fn create_dir(path: &Path) -> Result<()> {
+    fs::create_dir_all(path).map_err(|source|
+        Error::Io { path: path.to_path_buf(), source }
+    )
+}
+
+// insert the line into a block of lines which are ordered by day. day_of tells the day
+// of a line, or None if the line is not part of the block
+fn insert_ordered<F>(content: &str, line: &str, day: u8, day_of: F) -> Option<String> where F: Fn(&str) -> Option<u8> {
+    let mut lines: Vec<&str> = content.lines().collect();
+    let days: Vec<(usize, u8)> = lines.iter().enumerate()
+        .filter_map(|(i, l)| day_of(l).map(|d| (i, d)))
+        .collect();
+
+    let (first, _) = *days.first()?;
+    let index = days.iter().filter(|(_, d)| *d < day).map(|(i, _)| i + 1).next_back().unwrap_or(first);
+    lines.insert(index, line);
+
+    Some(lines.join("\n") + "\n")
+}
+
+fn day_of_dependency(line: &str) -> Option<u8> {
+    let rest = line.strip_prefix("day-")?;
+    let (day, _) = rest.split_once(" = ")?;
+
+    day.parse().ok()
+}
+
+fn day_of_registry_entry(line: &str) -> Option<u8> {
+    let rest = line.trim().strip_prefix("&day_")?;
+    let (day, _) = rest.split_once("::")?;
+
+    day.parse().ok()
+}
+
+// the registry has a list for every extension of a day as well, a new day only goes into the
+// one of all puzzles, between its header and the closing ]; The fuzz target has a copy of it
+fn insert_puzzle(content: &str, entry: &str, day: u8) -> Option<String> {
+    let start = content.find("const PUZZLES")?;
+    let end = start + content[start..].find("\n];")? + 1;
+    let block = insert_ordered(&content[start..end], entry, day, day_of_registry_entry)?;
+
+    Some(format!("{}{}{}", &content[..start], block, &content[end..]))
+}
+
+// the file with the line inserted, None from insert if there is nowhere to put it
+fn edit<F>(path: PathBuf, missing: &str, insert: F) -> Result<(PathBuf, String)> where F: Fn(&str) -> Option<String> {
+    let content = input::read_input(&path)?;
+    let content = insert(&content).ok_or_else(||
+        Error::Io { path: path.clone(), source: io::Error::other(format!("no {} found", missing)) }
+    )?;
+
+    Ok((path, content))
+}
+
+// the runner and the fuzz target both depend on every day and list it with the puzzles. The
+// files are only read here, so that nothing is changed if one of them has no place for the day
+fn register(root: &Path, day: u8) -> Result<Vec<(PathBuf, String)>> {
+    let dependency = format!("day-{} = {{ path = \"../day-{}\" }}", day, day);
+    let entry = format!("    &day_{}::Day{},", day, day);
+
+    let mut edits: Vec<(PathBuf, String)> = Vec::new();
+    for (manifest, puzzles) in [
+        (root.join("aoc").join("Cargo.toml"), root.join("aoc").join("src").join("registry.rs")),
+        (root.join("fuzz").join("Cargo.toml"), root.join("fuzz").join("fuzz_targets").join("parse.rs"))
+    ] {
+        edits.push(edit(manifest, "day dependencies", |content| insert_ordered(content, &dependency, day, day_of_dependency))?);
+        edits.push(edit(puzzles, "puzzles", |content| insert_puzzle(content, &entry, day))?);
+    }
+
+    Ok(edits)
+}
+
+fn create_day(dir: &Path, day: u8) -> Result<()> {
+    let fill = |template: &str| template.replace("$DAY", &day.to_string());
+
+    create_dir(&dir.join("src"))?;
+    create_dir(&dir.join("fixtures"))?;
+    write(&dir.join("Cargo.toml"), &fill(CARGO_TOML))?;
+    write(&dir.join("src").join("lib.rs"), &fill(LIB_RS))?;
+    write(&dir.join("fixtures").join("example.txt"), "")?;
+    write(&dir.join("fixtures").join("answers.txt"), ANSWERS)
+}
+
+// creates the crate of a new day with an empty solution and example fixtures, and
+// adds it to the runner and the fuzz target
+pub fn new_day(day: u8) -> Result<PathBuf> {
+    scaffold(&workspace_root()?, day)
+}
+
+fn scaffold(root: &Path, day: u8) -> Result<PathBuf> {
+    let dir = root.join(format!("day-{}", day));
+    if dir.exists() {
+        return Err(Error::Usage(format!("{} already exists, not touching it", dir.display())));
+    }
+
+    // a day which cannot be registered is not left behind half done
+    let edits = match create_day(&dir, day).and_then(|()| register(root, day)) {
+        Ok(edits) => edits,
+        Err(e) => {
+            let _ = fs::remove_dir_all(&dir);
+            return Err(e);
+        }
+    };
+    for (path, content) in edits {
+        write(&path, &content)?;
+    }
+
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::fetch::tests::temp_dir;
+
+    use super::{day_of_dependency, day_of_registry_entry, insert_ordered, insert_puzzle, scaffold};
+
+    #[test]
+    fn test_insert_dependency() {
+        let manifest = "[dependencies]\nclap.workspace = true\n\nday-1 = { path = \"../day-1\" }\nday-3 = { path = \"../day-3\" }\n\n[lints]\n";
+        let inserted = insert_ordered(manifest, "day-2 = { path = \"../day-2\" }", 2, day_of_dependency).unwrap();
+
+        assert_eq!(inserted, "[dependencies]\nclap.workspace = true\n\nday-1 = { path = \"../day-1\" }\nday-2 = { path = \"../day-2\" }\nday-3 = { path = \"../day-3\" }\n\n[lints]\n");
+    }
+
+    #[test]
+    fn test_insert_registry_entry() {
+        let registry = "pub const PUZZLES: &[&dyn Puzzle] = &[\n    &day_2::Day2,\n    &day_10::Day10,\n];\n";
+
+        let first = insert_ordered(registry, "    &day_1::Day1,", 1, day_of_registry_entry).unwrap();
+        assert_eq!(first, "pub const PUZZLES: &[&dyn Puzzle] = &[\n    &day_1::Day1,\n    &day_2::Day2,\n    &day_10::Day10,\n];\n");
+
+        let last = insert_ordered(registry, "    &day_17::Day17,", 17, day_of_registry_entry).unwrap();
+        assert_eq!(last, "pub const PUZZLES: &[&dyn Puzzle] = &[\n    &day_2::Day2,\n    &day_10::Day10,\n    &day_17::Day17,\n];\n");
+    }
+
+    #[test]
+    fn test_insert_puzzle() {
+        // the real registry, whose other lists must stay as they are
+        let registry = include_str!("registry.rs");
+        let (puzzles, others) = registry.split_once("\n];\n").unwrap();
+
+        for day in [17, 20, 22, 25] {
+            let entry = format!("    &day_{}::Day{},", day, day);
+            let inserted = insert_puzzle(registry, &entry, day).unwrap();
+            let (new_puzzles, new_others) = inserted.split_once("\n];\n").unwrap();
+
+            assert_eq!(new_others, others);
+            assert_eq!(new_puzzles.lines().filter(|line| *line != entry).collect::<Vec<&str>>(), puzzles.lines().collect::<Vec<&str>>());
+
+            let days: Vec<u8> = new_puzzles.lines().filter_map(day_of_registry_entry).collect();
+            assert!(days.contains(&day) && days.windows(2).all(|pair| pair[0] < pair[1]));
+        }
+    }
+
+    #[test]
+    fn test_insert_fuzz_puzzle() {
+        let target = include_str!("../../fuzz/fuzz_targets/parse.rs");
+        let inserted = insert_puzzle(target, "    &day_17::Day17,", 17).unwrap();
+
+        assert!(inserted.contains("    &day_16::Day16,\n    &day_17::Day17,\n    &day_18::Day18,\n"));
+    }
+
+    #[test]
+    fn test_scaffold() {
+        let root = temp_dir("scaffold");
+        for dir in ["aoc/src", "fuzz/fuzz_targets"] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        let manifest = "[dependencies]\nday-1 = { path = \"../day-1\" }\n";
+        let puzzles = "const PUZZLES: &[&dyn Puzzle] = &[\n    &day_1::Day1,\n];\n";
+        fs::write(root.join("aoc/Cargo.toml"), manifest).unwrap();
+        fs::write(root.join("aoc/src/registry.rs"), format!("pub {}", puzzles)).unwrap();
+        fs::write(root.join("fuzz/Cargo.toml"), manifest).unwrap();
+
+        // the fuzz target has no list of puzzles, so the new day is taken back again
+        fs::write(root.join("fuzz/fuzz_targets/parse.rs"), "").unwrap();
+        assert!(scaffold(&root, 2).is_err());
+        assert!(!root.join("day-2").exists());
+        assert_eq!(fs::read_to_string(root.join("aoc/Cargo.toml")).unwrap(), manifest);
+
+        fs::write(root.join("fuzz/fuzz_targets/parse.rs"), puzzles).unwrap();
+        assert_eq!(scaffold(&root, 2).unwrap(), root.join("day-2"));
+        assert!(root.join("day-2/src/lib.rs").exists());
+        assert!(fs::read_to_string(root.join("fuzz/Cargo.toml")).unwrap().contains("day-2 = { path = \"../day-2\" }"));
+        assert!(fs::read_to_string(root.join("fuzz/fuzz_targets/parse.rs")).unwrap().contains("    &day_2::Day2,\n];"));
+        assert!(fs::read_to_string(root.join("aoc/src/registry.rs")).unwrap().contains("    &day_2::Day2,\n];"));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
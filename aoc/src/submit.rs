@@ -0,0 +1,202 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use aoc_common::{input, Error, Part, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+use crate::fetch::USER_AGENT;
+
+// what the server said about an answer
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong"
+        };
+
+        write!(f, "{}", text)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    // seconds since the unix epoch
+    pub timestamp: u64
+}
+
+// every answer which was ever submitted, so that we never send the same wrong one twice
+#[derive(Debug, Default)]
+pub struct Ledger {
+    submissions: Vec<Submission>
+}
+
+impl Ledger {
+    // a missing file is an empty ledger
+    pub fn read(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Ledger::default());
+        }
+
+        let content = input::read_input(path)?;
+        let submissions = serde_json::from_str(&content).map_err(|e|
+            Error::Io { path: path.to_path_buf(), source: io::Error::from(e) }
+        )?;
+
+        Ok(Ledger { submissions })
+    }
+
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(&self.submissions).map_err(|e|
+            Error::Io { path: path.to_path_buf(), source: io::Error::from(e) }
+        )?;
+
+        fs::write(path, json + "\n").map_err(|source|
+            Error::Io { path: path.to_path_buf(), source }
+        )
+    }
+
+    pub fn record(&mut self, day: u8, part: Part, answer: &str, verdict: Verdict) {
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+
+        self.submissions.push(Submission { day, part: part.number(), answer: answer.to_string(), verdict, timestamp });
+    }
+
+    fn of(&self, day: u8, part: Part) -> impl Iterator<Item = &Submission> {
+        self.submissions.iter().filter(move |s| s.day == day && s.part == part.number())
+    }
+
+    // why the answer must not be submitted, if the ledger already knows better
+    pub fn rejection(&self, day: u8, part: Part, answer: &str) -> Option<String> {
+        if let Some(correct) = self.of(day, part).find(|s| s.verdict == Verdict::Correct) {
+            return if correct.answer == answer {
+                Some(String::from("it was already accepted"))
+            } else {
+                Some(format!("the part is already solved with {}", correct.answer))
+            };
+        }
+
+        if let Some(earlier) = self.of(day, part).find(|s| s.answer == answer) {
+            return Some(format!("it was already submitted and was {}", earlier.verdict));
+        }
+
+        // the bounds only work for numbers
+        let number = answer.parse::<i128>().ok()?;
+        let numbers = |verdict: Verdict| self.of(day, part)
+            .filter(move |s| s.verdict == verdict)
+            .filter_map(|s| s.answer.parse::<i128>().ok());
+
+        if let Some(high) = numbers(Verdict::TooHigh).filter(|&high| number >= high).min() {
+            return Some(format!("{} was already too high", high));
+        }
+        if let Some(low) = numbers(Verdict::TooLow).filter(|&low| number <= low).max() {
+            return Some(format!("{} was already too low", low));
+        }
+
+        None
+    }
+}
+
+// the server answers with a whole page, only the sentence in it matters
+pub fn parse_response(page: &str) -> Result<Verdict> {
+    if page.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if page.contains("your answer is too high") {
+        Ok(Verdict::TooHigh)
+    } else if page.contains("your answer is too low") {
+        Ok(Verdict::TooLow)
+    } else if page.contains("That's not the right answer") {
+        Ok(Verdict::Wrong)
+    } else if page.contains("You gave an answer too recently") {
+        Err(Error::Usage(String::from("the server asks to wait before submitting again")))
+    } else if page.contains("You don't seem to be solving the right level") {
+        Err(Error::Usage(String::from("the part is already solved or not unlocked yet")))
+    } else {
+        Err(Error::Usage(String::from("the server sent a response without a verdict")))
+    }
+}
+
+pub fn post(config: &Config, config_path: &Path, day: u8, part: Part, answer: &str) -> Result<Verdict> {
+    let session = config.session(config_path)?;
+    let url = config.url(&format!("day/{}/answer", day));
+    let level = part.number().to_string();
+
+    let response = ureq::post(&url)
+        .set("Cookie", &format!("session={}", session))
+        .set("User-Agent", USER_AGENT)
+        .send_form(&[("level", level.as_str()), ("answer", answer)])
+        .map_err(|e| Error::Http { url: url.clone(), message: e.to_string() })?;
+    let page = response.into_string().map_err(|e|
+        Error::Http { url: url.clone(), message: e.to_string() }
+    )?;
+
+    parse_response(&page)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Part;
+
+    use super::{parse_response, post, Ledger, Verdict};
+    use crate::config::Config;
+    use crate::fetch::tests::stub_server;
+
+    #[test]
+    fn test_rejection() {
+        let mut ledger = Ledger::default();
+        ledger.record(5, Part::One, "100", Verdict::TooHigh);
+        ledger.record(5, Part::One, "20", Verdict::TooLow);
+        ledger.record(5, Part::One, "50", Verdict::Wrong);
+
+        assert_eq!(ledger.rejection(5, Part::One, "50"), Some(String::from("it was already submitted and was wrong")));
+        assert_eq!(ledger.rejection(5, Part::One, "120"), Some(String::from("100 was already too high")));
+        assert_eq!(ledger.rejection(5, Part::One, "20"), Some(String::from("it was already submitted and was too low")));
+        assert_eq!(ledger.rejection(5, Part::One, "3"), Some(String::from("20 was already too low")));
+        assert_eq!(ledger.rejection(5, Part::One, "60"), None);
+        assert_eq!(ledger.rejection(5, Part::Two, "120"), None);
+
+        ledger.record(5, Part::One, "60", Verdict::Correct);
+        assert_eq!(ledger.rejection(5, Part::One, "60"), Some(String::from("it was already accepted")));
+        assert_eq!(ledger.rejection(5, Part::One, "61"), Some(String::from("the part is already solved with 60")));
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(parse_response("<p>That's the right answer! You are one gold star closer</p>").unwrap(), Verdict::Correct);
+        assert_eq!(parse_response("<p>That's not the right answer; your answer is too low.</p>").unwrap(), Verdict::TooLow);
+        assert_eq!(parse_response("<p>That's not the right answer. If you're stuck</p>").unwrap(), Verdict::Wrong);
+        assert!(parse_response("<p>You gave an answer too recently</p>").is_err());
+    }
+
+    #[test]
+    fn test_post() {
+        let (base_url, requests) = stub_server("<p>That's not the right answer; your answer is too high.</p>");
+        let config = Config { session: Some(String::from("secret")), base_url };
+
+        let verdict = post(&config, "aoc.toml".as_ref(), 7, Part::Two, "5905").unwrap();
+        assert_eq!(verdict, Verdict::TooHigh);
+
+        let request = requests.recv().unwrap();
+        assert_eq!(request[0], "POST /2023/day/7/answer HTTP/1.1");
+        assert!(request.iter().any(|h| h == "Cookie: session=secret"));
+        assert_eq!(request.last().unwrap(), "level=2&answer=5905");
+    }
+}
@@ -0,0 +1,102 @@
+use aoc_common::{input, Answer, Error, Result, Solution};
+
+pub struct Day1;
+
+// the digits as they may be spelled out in part 2, in the order of their values
+const WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Trebuchet?!";
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines = input::lines(input);
+
+        // part 1 only counts the plain digits, but the examples of part 2 have lines with
+        // nothing else than spelled ones
+        for line in lines.iter() {
+            if extract_spelled_number(line).is_none() {
+                return Err(Error::parse(line, "expected a digit in the line"));
+            }
+        }
+
+        Ok(lines.iter().map(|line| line.to_string()).collect())
+    }
+
+    fn part1(lines: &Self::Input) -> Answer {
+        let numbers: Option<Vec<u32>> = lines.iter().map( |line|
+            extract_number(line)
+        ).collect();
+
+        match numbers {
+            Some(numbers) => numbers.iter().sum::<u32>().into(),
+            None => Answer::Unsolved
+        }
+    }
+
+    fn part2(lines: &Self::Input) -> Answer {
+        let numbers: Option<Vec<u32>> = lines.iter().map( |line|
+            extract_spelled_number(line)
+        ).collect();
+
+        match numbers {
+            Some(numbers) => numbers.iter().sum::<u32>().into(),
+            None => Answer::Unsolved
+        }
+    }
+}
+
+// the first and the last digit of the line as a two digit number, None without a digit
+fn extract_number(line: &str) -> Option<u32> {
+    let first = line.chars().find(|c| c.is_ascii_digit())?;
+    let last = line.chars().rev().find(|c| c.is_ascii_digit())?;
+
+    Some(first.to_digit(10)? * 10 + last.to_digit(10)?)
+}
+
+// the digit the text starts with, as a digit or spelled out
+fn digit_at_start(text: &str) -> Option<u32> {
+    let first_char = text.chars().next()?;
+    if first_char.is_ascii_digit() {
+        return first_char.to_digit(10);
+    }
+
+    WORDS.iter().position(|word| text.starts_with(word)).map(|i| i as u32 + 1)
+}
+
+// like extract_number, but digits might also be spelled out. The line is walked by chars, so
+// every slice starts on a char boundary
+fn extract_spelled_number(line: &str) -> Option<u32> {
+    let first = line.char_indices().find_map(|(i, _)| digit_at_start(&line[i..]))?;
+    // spelled digits may overlap like in "twone", the last one is the one starting last
+    let last = line.char_indices().rev().find_map(|(i, _)| digit_at_start(&line[i..]))?;
+
+    Some(first * 10 + last)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{examples, Answer, Solution};
+
+    use super::Day1;
+
+    #[test]
+    fn test_examples() {
+        examples::check(&Day1, env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_odd_lines() {
+        let e = Day1::parse("1abc2\nabc").unwrap_err();
+        assert_eq!(e.to_string(), "expected a digit in the line at 'abc'");
+
+        let lines = Day1::parse("aé1\nséven8ninê").unwrap();
+        assert_eq!(Day1::part1(&lines), Answer::Number(11 + 88));
+        assert_eq!(Day1::part2(&lines), Answer::Number(11 + 88));
+
+        // only spelled digits are enough to parse, but part 1 has nothing to count then
+        assert_eq!(Day1::part1(&Day1::parse("eightwo").unwrap()), Answer::Unsolved);
+    }
+}
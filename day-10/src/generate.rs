@@ -0,0 +1,281 @@
+use std::collections::{HashMap, VecDeque};
+
+use aoc_common::generate::Generator;
+use aoc_common::random::Rng;
+use aoc_common::{Answer, Direction, Part};
+
+use crate::Day10;
+
+const JUNK: [char; 10] = ['.', '.', '.', '.', '|', '-', 'L', 'J', '7', 'F'];
+
+// the directions a pipe is open to
+fn openings(c: char) -> &'static [Direction] {
+    match c {
+        '|' => &[Direction::Up, Direction::Down],
+        '-' => &[Direction::Left, Direction::Right],
+        'L' => &[Direction::Up, Direction::Right],
+        'J' => &[Direction::Up, Direction::Left],
+        '7' => &[Direction::Down, Direction::Left],
+        'F' => &[Direction::Down, Direction::Right],
+        _ => &[]
+    }
+}
+
+fn pipe(a: Direction, b: Direction) -> char {
+    ['|', '-', 'L', 'J', '7', 'F'].into_iter()
+        .find(|&c| openings(c).contains(&a) && openings(c).contains(&b))
+        .unwrap()
+}
+
+// A blob of unit squares on a width x height board. The outline of a blob without
+// holes and without squares which only touch at a corner is a simple loop through
+// the corners, which becomes the pipe loop
+struct Blob {
+    width: i64,
+    height: i64,
+    squares: Vec<bool>
+}
+
+impl Blob {
+    fn contains(&self, x: i64, y: i64) -> bool {
+        x >= 0 && y >= 0 && x < self.width && y < self.height && self.squares[(y * self.width + x) as usize]
+    }
+
+    fn set(&mut self, x: i64, y: i64, value: bool) {
+        self.squares[(y * self.width + x) as usize] = value;
+    }
+
+    // two squares meeting only at the corner (x, y)
+    fn pinched(&self, x: i64, y: i64) -> bool {
+        let (a, b) = (self.contains(x - 1, y - 1), self.contains(x, y - 1));
+        let (c, d) = (self.contains(x - 1, y), self.contains(x, y));
+
+        (a && d && !b && !c) || (b && c && !a && !d)
+    }
+
+    // every free square can reach the border through free squares
+    fn without_holes(&self) -> bool {
+        let mut outside = vec![false; self.squares.len()];
+        let mut queue: VecDeque<(i64, i64)> = VecDeque::new();
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let border = x == 0 || y == 0 || x == self.width - 1 || y == self.height - 1;
+                if border && !self.contains(x, y) {
+                    outside[(y * self.width + x) as usize] = true;
+                    queue.push_back((x, y));
+                }
+            }
+        }
+
+        while let Some((x, y)) = queue.pop_front() {
+            for d in Direction::ALL {
+                let v = d.vector::<i64>();
+                let (nx, ny) = (x + v.x, y + v.y);
+                if nx < 0 || ny < 0 || nx >= self.width || ny >= self.height || self.contains(nx, ny) {
+                    continue;
+                }
+                if !outside[(ny * self.width + nx) as usize] {
+                    outside[(ny * self.width + nx) as usize] = true;
+                    queue.push_back((nx, ny));
+                }
+            }
+        }
+
+        (0..self.squares.len()).all(|i| self.squares[i] || outside[i])
+    }
+
+    fn grow(rng: &mut Rng, width: i64, height: i64) -> Self {
+        let mut blob = Blob { width, height, squares: vec![false; (width * height) as usize] };
+        blob.set(rng.range(0..width), rng.range(0..height), true);
+
+        let target = rng.range(1..width * height / 2 + 2);
+        let mut count = 1;
+        for _ in 0..width * height * 20 {
+            if count >= target {
+                break;
+            }
+
+            let (x, y) = (rng.range(0..width), rng.range(0..height));
+            let v = rng.pick(&Direction::ALL).vector::<i64>();
+            let (nx, ny) = (x + v.x, y + v.y);
+            if !blob.contains(x, y) || nx < 0 || ny < 0 || nx >= width || ny >= height || blob.contains(nx, ny) {
+                continue;
+            }
+
+            blob.set(nx, ny, true);
+            let corners = [(nx, ny), (nx + 1, ny), (nx, ny + 1), (nx + 1, ny + 1)];
+            if corners.iter().any(|&(cx, cy)| blob.pinched(cx, cy)) || !blob.without_holes() {
+                blob.set(nx, ny, false);
+            } else {
+                count = count + 1;
+            }
+        }
+
+        blob
+    }
+
+    // the directions in which the outline leaves the corner (x, y)
+    fn outline(&self, x: i64, y: i64) -> Vec<Direction> {
+        let edge = |a: bool, b: bool| a != b;
+
+        Direction::ALL.into_iter().filter(|d| match d {
+            Direction::Up => edge(self.contains(x - 1, y - 1), self.contains(x, y - 1)),
+            Direction::Down => edge(self.contains(x - 1, y), self.contains(x, y)),
+            Direction::Left => edge(self.contains(x - 1, y - 1), self.contains(x - 1, y)),
+            Direction::Right => edge(self.contains(x, y - 1), self.contains(x, y))
+        }).collect()
+    }
+}
+
+// reads a generated map back into rows of chars
+fn parse(input: &str) -> Vec<Vec<char>> {
+    input.trim().lines().map(|line| line.chars().collect()).collect()
+}
+
+fn tile(map: &[Vec<char>], x: i64, y: i64) -> char {
+    if x < 0 || y < 0 || y as usize >= map.len() || x as usize >= map[y as usize].len() {
+        return '.';
+    }
+
+    map[y as usize][x as usize]
+}
+
+// where a tile connects to, the start connects to every pipe pointing at it
+fn connections(map: &[Vec<char>], x: i64, y: i64) -> Vec<Direction> {
+    let c = tile(map, x, y);
+    if c != 'S' {
+        return openings(c).to_vec();
+    }
+
+    Direction::ALL.into_iter().filter(|d| {
+        let v = d.vector::<i64>();
+        openings(tile(map, x + v.x, y + v.y)).contains(&d.reverse())
+    }).collect()
+}
+
+// distances of all loop tiles from the start
+fn walk_loop(map: &[Vec<char>]) -> HashMap<(i64, i64), usize> {
+    let start = map.iter().enumerate()
+        .find_map(|(y, row)| row.iter().position(|&c| c == 'S').map(|x| (x as i64, y as i64)))
+        .unwrap();
+
+    let mut distances: HashMap<(i64, i64), usize> = HashMap::new();
+    distances.insert(start, 0);
+    let mut queue = VecDeque::from([start]);
+    while let Some((x, y)) = queue.pop_front() {
+        for d in connections(map, x, y) {
+            let v = d.vector::<i64>();
+            let next = (x + v.x, y + v.y);
+            if !connections(map, next.0, next.1).contains(&d.reverse()) || distances.contains_key(&next) {
+                continue;
+            }
+
+            distances.insert(next, distances[&(x, y)] + 1);
+            queue.push_back(next);
+        }
+    }
+
+    distances
+}
+
+// every tile becomes a 3x3 block in which the loop is drawn. The blocks which cannot
+// be reached from the outside without crossing the loop are enclosed
+fn enclosed(map: &[Vec<char>], on_loop: &HashMap<(i64, i64), usize>) -> usize {
+    let height = map.len() as i64;
+    let width = map[0].len() as i64;
+    let (big_width, big_height) = (width * 3 + 2, height * 3 + 2);
+    let mut wall = vec![false; (big_width * big_height) as usize];
+
+    for &(x, y) in on_loop.keys() {
+        let (cx, cy) = (x * 3 + 2, y * 3 + 2);
+        wall[(cy * big_width + cx) as usize] = true;
+        for d in connections(map, x, y) {
+            let v = d.vector::<i64>();
+            wall[((cy + v.y) * big_width + cx + v.x) as usize] = true;
+        }
+    }
+
+    let mut outside = vec![false; wall.len()];
+    outside[0] = true;
+    let mut queue = VecDeque::from([(0i64, 0i64)]);
+    while let Some((x, y)) = queue.pop_front() {
+        for d in Direction::ALL {
+            let v = d.vector::<i64>();
+            let (nx, ny) = (x + v.x, y + v.y);
+            if nx < 0 || ny < 0 || nx >= big_width || ny >= big_height {
+                continue;
+            }
+
+            let i = (ny * big_width + nx) as usize;
+            if !wall[i] && !outside[i] {
+                outside[i] = true;
+                queue.push_back((nx, ny));
+            }
+        }
+    }
+
+    (0..height).flat_map(|y| (0..width).map(move |x| (x, y)))
+        .filter(|p| !on_loop.contains_key(p))
+        .filter(|&(x, y)| !outside[((y * 3 + 2) * big_width + x * 3 + 2) as usize])
+        .count()
+}
+
+impl Generator for Day10 {
+    // a pipe loop on a board of about size x size tiles, surrounded by junk pipes
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let board = size.max(2) as i64;
+        let blob = Blob::grow(rng, board, board);
+
+        // corner (x, y) of the blob is tile (x + 1, y + 1) of the map
+        let side = (board + 3) as usize;
+        let mut map: Vec<Vec<char>> = (0..side).map(|_| (0..side).map(|_| *rng.pick(&JUNK)).collect()).collect();
+        let mut corners: Vec<(i64, i64)> = Vec::new();
+        for y in 0..=board {
+            for x in 0..=board {
+                let outline = blob.outline(x, y);
+                if outline.len() == 2 {
+                    map[y as usize + 1][x as usize + 1] = pipe(outline[0], outline[1]);
+                    corners.push((x + 1, y + 1));
+                }
+            }
+        }
+
+        // the start may only be connected to its two loop neighbours
+        let (sx, sy) = *rng.pick(&corners);
+        let loop_directions = openings(map[sy as usize][sx as usize]);
+        map[sy as usize][sx as usize] = 'S';
+        for d in Direction::ALL {
+            let v = d.vector::<i64>();
+            let (nx, ny) = ((sx + v.x) as usize, (sy + v.y) as usize);
+            if !loop_directions.contains(&d) && openings(map[ny][nx]).contains(&d.reverse()) {
+                map[ny][nx] = '.';
+            }
+        }
+
+        map.iter().map(|row| row.iter().collect::<String>() + "\n").collect()
+    }
+
+    // part 1 by a search along the pipes, part 2 by a flood fill on a map of three
+    // times the size, so that there is room to squeeze between the pipes
+    fn reference(input: &str, part: Part) -> Answer {
+        let map = parse(input);
+        let on_loop = walk_loop(&map);
+
+        match part {
+            Part::One => on_loop.values().max().copied().unwrap_or(0).into(),
+            Part::Two => enclosed(&map, &on_loop).into()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate;
+
+    use crate::Day10;
+
+    #[test]
+    fn test_generated() {
+        generate::check(&Day10, 50, 8);
+    }
+}
@@ -0,0 +1,284 @@
+mod generate;
+mod picture;
+mod visualize;
+
+use std::collections::HashSet;
+use std::fmt;
+use aoc_common::{graph, Answer, Direction, Error, Grid, Result, Solution};
+use aoc_common::grid::Position;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Pipe Maze";
+
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Map::from(input)
+    }
+
+    fn part1(m: &Self::Input) -> Answer {
+        // the farthest point is half way around the loop
+        (m.compute_pipe_nodes().len() / 2).into()
+    }
+
+    fn part2(m: &Self::Input) -> Answer {
+        m.inner_nodes().len().into()
+    }
+}
+
+// assumption: The starting node has only 2 pipes connecting to it
+
+#[derive(Debug, PartialEq)]
+pub struct Map {
+    tiles: Grid<Type>,
+    start_node: Position
+}
+
+impl Map {
+    fn from(input: &str) -> Result<Self> {
+        let tiles = Grid::parse(input, |item, token| Type::from(item, token))?;
+
+        let Some(start_node) = tiles.find(|t| *t == Type::Start) else {
+            return Err(Error::parse(&input[input.len()..], "there is no starting node on the map"));
+        };
+
+        Ok(Map { tiles, start_node })
+    }
+
+    fn construct_pipe_map(&self) -> Grid<Type> {
+        let parts = self.compute_pipe_nodes();
+
+        let mut pipe_map = self.tiles.clone();
+        for position in self.tiles.positions() {
+            if !parts.contains(&position) {
+                // add everything else as void
+                pipe_map[position] = Type::Void;
+            }
+        }
+        // the start is a pipe like every other, crossing it counts as well
+        pipe_map[self.start_node] = self.start_type();
+
+        pipe_map
+    }
+
+    // the pipe under the starting node, found by the two pipes connecting to it
+    fn start_type(&self) -> Type {
+        let connected: Vec<Direction> = Direction::ALL.into_iter().filter(|&direction|
+            self.tiles.step(self.start_node, direction)
+                .is_some_and(|n| Type::Start.compatible(self.tiles[n], direction))
+        ).collect();
+
+        match connected[..] {
+            [Direction::Up, Direction::Down] => Type::NorthSouth,
+            [Direction::Right, Direction::Left] => Type::EastWest,
+            [Direction::Up, Direction::Right] => Type::NorthEast,
+            [Direction::Up, Direction::Left] => Type::NorthWest,
+            [Direction::Down, Direction::Left] => Type::SouthWest,
+            [Direction::Right, Direction::Down] => Type::SouthEast,
+            _ => Type::Start
+        }
+    }
+
+    fn inner_nodes(&self) -> Vec<Position> {
+        let new_map = self.construct_pipe_map();
+        let mut inner_nodes: Vec<Position> = Vec::new();
+
+        new_map.iter().for_each(|item| {
+            let t = item.1;
+            let p = item.0;
+
+            if *t != Type::Void {
+                return;
+            }
+
+            // from there, go to the left and count crossings with pipe parts
+            let mut crossings = 0;
+            let mut special_pipe: Vec<Type> = Vec::new();
+            for pipe_type in new_map.row(p.1)[0 .. p.0].iter().rev() {
+                if *pipe_type == Type::EastWest {
+                    continue;
+                }
+
+                if *pipe_type == Type::NorthSouth {
+                    crossings = crossings + 1;
+                } else {
+                    special_pipe.push(pipe_type.clone());
+                }
+            }
+            // check for FJ or L7 combinations
+            for pipe_pair in special_pipe.windows(2) {
+                if pipe_pair[0] == Type::NorthWest && pipe_pair[1] == Type::SouthEast {
+                    crossings = crossings + 1;
+                }
+
+                if pipe_pair[0] == Type::SouthWest && pipe_pair[1] == Type::NorthEast {
+                    crossings = crossings + 1;
+                }
+            }
+
+            if crossings % 2 == 1 {
+                inner_nodes.push(p.clone());
+            }
+        });
+
+        inner_nodes
+    }
+
+    fn compute_pipe_nodes(&self) -> HashSet<Position> {
+        let distances = graph::bfs(self.start_node, |n| self.possible_neighbours(n));
+
+        distances.into_keys().collect()
+    }
+
+    fn possible_neighbours(&self, n: Position) -> Vec<Position> {
+        let mut possible_neighbours: Vec<Position> = Vec::new();
+
+        for direction in Direction::ALL {
+            let Some(to_n) = self.tiles.step(n, direction) else { continue };
+            let f_type = self.tiles[n];
+            let t_type = self.tiles[to_n];
+
+            if f_type.compatible(t_type, direction) {
+                possible_neighbours.push(to_n);
+            }
+        }
+
+        possible_neighbours
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Type {
+    Start, // S
+    NorthSouth, // |
+    EastWest, // -
+    NorthEast, // L
+    NorthWest, // J
+    SouthWest, // 7
+    SouthEast, // F
+    Void // .
+}
+
+impl Type {
+    fn from(n: char, token: &str) -> Result<Self> {
+        match n {
+            'S' => Ok(Self::Start),
+            '|' => Ok(Self::NorthSouth),
+            '-' => Ok(Self::EastWest),
+            'L' => Ok(Self::NorthEast),
+            'J' => Ok(Self::NorthWest),
+            '7' => Ok(Self::SouthWest),
+            'F' => Ok(Self::SouthEast),
+            '.' => Ok(Self::Void),
+            _ => Err(Error::parse(token, "unknown tile"))
+        }
+    }
+
+    fn compatible(&self, other: Type, direction: Direction) -> bool {
+        match (self, direction) {
+            (_, _) if other == Self::Void => false,
+
+            (Self::NorthSouth, Direction::Left | Direction::Right) => false,
+            (Self::NorthSouth, Direction::Up) => other == Self::NorthSouth || other == Self::SouthEast || other == Self::SouthWest,
+            (Self::NorthSouth, Direction::Down) => other == Self::NorthSouth || other == Self::NorthEast || other == Self::NorthWest,
+
+            (Self::EastWest, Direction::Left) => other == Self::EastWest || other == Self::NorthEast || other == Self::SouthEast,
+            (Self::EastWest, Direction::Right) => other == Self::EastWest || other == Self::NorthWest || other == Self::SouthWest,
+            (Self::EastWest, Direction::Up | Direction::Down) => false,
+
+            (Self::NorthEast, Direction::Left | Direction::Down) => false,
+            (Self::NorthEast, Direction::Right) => other == Self::EastWest || other == Self::NorthWest || other == Self::SouthWest,
+            (Self::NorthEast, Direction::Up) => other ==  Self::NorthSouth || other == Self::SouthWest || other == Self::SouthEast,
+
+            (Self::NorthWest, Direction::Right | Direction::Down) => false,
+            (Self::NorthWest, Direction::Left) => other == Self::EastWest || other == Self::NorthEast || other == Self::SouthEast,
+            (Self::NorthWest, Direction::Up) => other == Self::NorthSouth || other == Self::SouthWest || other == Self::SouthEast,
+
+            (Self::SouthWest, Direction::Right | Direction::Up) => false,
+            (Self::SouthWest, Direction::Left) => other == Self::EastWest || other == Self::NorthEast || other == Self::SouthEast,
+            (Self::SouthWest, Direction::Down) => other == Self::NorthSouth || other == Self::NorthEast || other == Self::NorthWest,
+
+            (Self::SouthEast, Direction::Left | Direction::Up) => false,
+            (Self::SouthEast, Direction::Right) => other == Self::EastWest || other == Self::NorthWest || other == Self::SouthWest,
+            (Self::SouthEast, Direction::Down) => other == Self::NorthSouth || other == Self::NorthEast || other == Self::NorthWest,
+
+            (Self::Start, Direction::Left) => !(other == Self::NorthSouth || other == Self::NorthWest || other == Self::SouthWest),
+            (Self::Start, Direction::Right) => !(other == Self::NorthSouth || other == Self::NorthEast || other == Self::SouthEast),
+            (Self::Start, Direction::Up) => !(other == Self::EastWest || other == Self::NorthEast || other == Self::NorthWest),
+            (Self::Start, Direction::Down) => !(other == Self::EastWest || other == Self::SouthWest || other == Self::SouthEast),
+
+            (Self::Void, _) => false
+        }
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.tiles)
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tile = match self {
+            Self::Start => 'S',
+            Self::NorthSouth => '|',
+            Self::EastWest => '-',
+            Self::NorthEast => 'L',
+            Self::NorthWest => 'J',
+            Self::SouthWest => '7',
+            Self::SouthEast => 'F',
+            Self::Void => '.'
+        };
+
+        write!(f, "{}", tile)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::visualize::Visualize;
+    use aoc_common::{examples, Grid, Part, Solution};
+    use proptest::prelude::*;
+
+    use super::{Day10, Map, Type};
+
+    #[test]
+    fn test_examples() {
+        examples::check(&Day10, env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_visualize() {
+        let map = Day10::parse("..........\n.S------7.\n.|F----7|.\n.||....||.\n.||....||.\n.|L-7F-J|.\n.|..||..|.\n.L--JL--J.\n..........").unwrap();
+        let frames = Day10::frames(&map, Part::Two);
+
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].render(false), "4 tiles inside the loop\n          \n ┌──────┐ \n │┌────┐│ \n ││    ││ \n ││    ││ \n │└─┐┌─┘│ \n │██││██│ \n └──┘└──┘ \n          \n");
+    }
+
+    // a map of random pipes with a single start
+    fn map() -> impl Strategy<Value = Map> {
+        let pipes = vec![Type::NorthSouth, Type::EastWest, Type::NorthEast, Type::NorthWest, Type::SouthWest, Type::SouthEast, Type::Void];
+
+        (1..10usize, 1..10usize).prop_flat_map(move |(width, height)|
+            (prop::collection::vec(prop::sample::select(pipes.clone()), width * height), 0..width, 0..height)
+                .prop_map(move |(cells, x, y)| {
+                    let mut tiles = Grid::new(width, height, cells);
+                    tiles[(x, y)] = Type::Start;
+
+                    Map { tiles, start_node: (x, y) }
+                })
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(map in map()) {
+            prop_assert_eq!(Day10::parse(&map.to_string()).unwrap(), map);
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use aoc_common::grid::Position;
+use aoc_common::image::{Draw, Image, Pixmap, Rgb};
+use aoc_common::{Direction, Part};
+
+use crate::{Day10, Map, Type};
+
+// every tile is drawn as SCALE x SCALE pixels, with the pipe running through its middle
+const SCALE: usize = 3;
+
+const PIPE: Rgb = Rgb(40, 40, 40);
+const START: Rgb = Rgb(220, 30, 30);
+const JUNK: Rgb = Rgb(200, 200, 200);
+const INSIDE: Rgb = Rgb(60, 180, 75);
+
+// the directions a pipe connects to
+fn openings(t: Type) -> &'static [Direction] {
+    match t {
+        Type::NorthSouth => &[Direction::Up, Direction::Down],
+        Type::EastWest => &[Direction::Left, Direction::Right],
+        Type::NorthEast => &[Direction::Up, Direction::Right],
+        Type::NorthWest => &[Direction::Up, Direction::Left],
+        Type::SouthWest => &[Direction::Down, Direction::Left],
+        Type::SouthEast => &[Direction::Down, Direction::Right],
+        Type::Start | Type::Void => &[]
+    }
+}
+
+fn pipe(pixmap: &mut Pixmap, position: Position, t: Type, colour: Rgb) {
+    let (x, y) = (position.0 * SCALE + 1, position.1 * SCALE + 1);
+
+    pixmap.set(x, y, colour);
+    for direction in openings(t) {
+        let arm = direction.vector::<i64>();
+        pixmap.set((x as i64 + arm.x) as usize, (y as i64 + arm.y) as usize, colour);
+    }
+}
+
+impl Draw for Day10 {
+    // the loop on white, other pipes in light grey. Part 2 fills the tiles inside the loop
+    fn draw(m: &Map, part: Part) -> Option<Image> {
+        let pipes = m.construct_pipe_map();
+        let mut pixmap = Pixmap::new(pipes.width() * SCALE, pipes.height() * SCALE, Rgb::WHITE);
+
+        if part == Part::Two {
+            for (x, y) in m.inner_nodes() {
+                pixmap.fill(x * SCALE, y * SCALE, SCALE, INSIDE);
+            }
+        }
+
+        for (position, &t) in m.tiles.iter() {
+            if pipes[position] != Type::Void {
+                let colour = if position == m.start_node { START } else { PIPE };
+                pipe(&mut pixmap, position, pipes[position], colour);
+            } else if t != Type::Void && t != Type::Start {
+                pipe(&mut pixmap, position, t, JUNK);
+            }
+        }
+
+        Some(Image::Ppm(pixmap))
+    }
+}
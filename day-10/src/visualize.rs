@@ -0,0 +1,56 @@
+use std::collections::HashSet;
+
+use aoc_common::grid::Position;
+use aoc_common::visualize::{Cell, Colour, Frame, Visualize};
+use aoc_common::Part;
+
+use crate::{Day10, Map, Type};
+
+// the pipes drawn as box drawing lines
+fn symbol(t: Type) -> char {
+    match t {
+        Type::Start => 'S',
+        Type::NorthSouth => '│',
+        Type::EastWest => '─',
+        Type::NorthEast => '└',
+        Type::NorthWest => '┘',
+        Type::SouthWest => '┐',
+        Type::SouthEast => '┌',
+        Type::Void => ' '
+    }
+}
+
+impl Visualize for Day10 {
+    // the loop in yellow with the start in red, pipes which are not part of it as grey dots.
+    // Part 2 fills the tiles inside the loop as well
+    fn frames(m: &Map, part: Part) -> Vec<Frame> {
+        let pipes = m.construct_pipe_map();
+        let inner: HashSet<Position> = match part {
+            Part::One => HashSet::new(),
+            Part::Two => m.inner_nodes().into_iter().collect()
+        };
+
+        let mut cells = pipes.map(|&t| Cell::coloured(symbol(t), Colour::Yellow));
+        for (position, &t) in pipes.iter() {
+            if t != Type::Void {
+                continue;
+            }
+
+            cells[position] = if inner.contains(&position) {
+                Cell::coloured('█', Colour::Green)
+            } else if m.tiles[position] != Type::Void {
+                Cell::coloured('·', Colour::Grey)
+            } else {
+                Cell::plain(' ')
+            };
+        }
+        cells[m.start_node] = Cell::coloured(symbol(pipes[m.start_node]), Colour::Red);
+
+        let caption = match part {
+            Part::One => format!("the loop, {} tiles long", m.compute_pipe_nodes().len()),
+            Part::Two => format!("{} tiles inside the loop", inner.len())
+        };
+
+        vec![Frame::new(caption, cells)]
+    }
+}
@@ -0,0 +1,147 @@
+use std::collections::HashSet;
+use std::fmt;
+use aoc_common::interval::IntervalSet;
+use aoc_common::{Answer, Error, Grid, Point, Result, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Cosmic Expansion";
+
+    type Input = Galaxy;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let image = Grid::parse(input, |item, token| match item {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(Error::parse(token, "expected '#' or '.'"))
+        })?;
+
+        Ok(Galaxy::from(&image))
+    }
+
+    fn part1(g: &Self::Input) -> Answer {
+        g.distance_sum(2).into()
+    }
+
+    fn part2(g: &Self::Input) -> Answer {
+        g.distance_sum(1_000_000).into()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Galaxy {
+    stars: HashSet<Point<u64>>,
+    empty_rows: IntervalSet,
+    empty_columns: IntervalSet
+}
+
+impl Galaxy {
+    // image tells for every position whether there is a star
+    fn from(image: &Grid<bool>) -> Self {
+        let stars: HashSet<Point<u64>> = image.iter().filter(|(_, &star)| star).map(|(p, _)|
+            Point::new(p.0 as u64, p.1 as u64)
+        ).collect();
+
+        // find empty columns
+        let empty_columns: IntervalSet = image.columns().map(|mut column|
+            column.all(|&star| !star)
+        ).enumerate().filter(|(_, empty)| *empty).map(|(x, _)| x as i64).collect();
+
+        // find empty rows
+        let empty_rows: IntervalSet = image.rows().map(|row|
+            row.iter().all(|&star| !star)
+        ).enumerate().filter(|(_, empty)| *empty).map(|(y, _)| y as i64).collect();
+
+        Galaxy { stars, empty_rows, empty_columns }
+    }
+
+    fn distance_sum(&self, expansion: u64) -> u64 {
+        let mut sum: u64 = self.all_pair_distance(expansion).iter().sum();
+        sum = sum / 2u64; // i counted everything twice
+
+        sum
+    }
+
+    fn all_pair_distance(&self, expansion: u64) -> Vec<u64> {
+        let mut distances: Vec<u64> = Vec::new();
+
+        for star1 in self.stars.iter() {
+            for star2 in self.stars.iter() {
+                if star1 == star2 {
+                    continue;
+                }
+
+                let dist = self.compute_distance(star1, star2, expansion);
+                distances.push(dist);
+            }
+        }
+
+        distances
+    }
+
+    fn compute_distance(&self, from: &Point<u64>, to: &Point<u64>, expansion: u64) -> u64 {
+        let distance = from.manhattan(to);
+
+        // the universe is expanding
+        // how many empty rows/columns are in between both
+
+        let min_x = from.x.min(to.x);
+        let max_x = from.x.max(to.x);
+
+        let min_y = from.y.min(to.y);
+        let max_y = from.y.max(to.y);
+
+        // count rows between min/max
+        let rows_between = self.empty_rows.count_in(min_y as i64 + 1..max_y as i64);
+        let columns_between = self.empty_columns.count_in(min_x as i64 + 1..max_x as i64);
+
+        // every empty row/column is replaced by expansion many, so it adds expansion - 1
+        distance
+            + rows_between*(expansion - 1)
+            + columns_between*(expansion - 1)
+    }
+}
+
+// the image again. It reaches as far as the last star or the last empty row and column
+impl fmt::Display for Galaxy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self.stars.iter().map(|star| star.x).chain(self.empty_columns.max().map(|x| x as u64)).max().map(|x| x + 1).unwrap_or(0);
+        let height = self.stars.iter().map(|star| star.y).chain(self.empty_rows.max().map(|y| y as u64)).max().map(|y| y + 1).unwrap_or(0);
+
+        let mut image = Grid::filled(width as usize, height as usize, '.');
+        for star in self.stars.iter() {
+            image[(star.x as usize, star.y as usize)] = '#';
+        }
+
+        write!(f, "{}", image)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{examples, Grid, Solution};
+    use proptest::prelude::*;
+
+    use super::{Day11, Galaxy};
+
+    #[test]
+    fn test_examples() {
+        examples::check(&Day11, env!("CARGO_MANIFEST_DIR"));
+    }
+
+    fn galaxy() -> impl Strategy<Value = Galaxy> {
+        (1..12usize, 1..12usize).prop_flat_map(|(width, height)|
+            prop::collection::vec(prop::bool::weighted(0.2), width * height)
+                .prop_map(move |cells| Galaxy::from(&Grid::new(width, height, cells)))
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(galaxy in galaxy()) {
+            prop_assert_eq!(Day11::parse(&galaxy.to_string()).unwrap(), galaxy);
+        }
+    }
+}
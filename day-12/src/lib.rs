@@ -0,0 +1,144 @@
+use std::fmt;
+use aoc_common::parse::{self, Parser};
+use aoc_common::{input, parallel, Answer, Error, Result, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Hot Springs";
+
+    type Input = Vec<SpringRow>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines = input::lines(input);
+
+        let spring_rows: Vec<SpringRow> = lines.iter().map(|line|
+            SpringRow::from(line)
+        ).collect::<Result<_>>()?;
+
+        Ok(spring_rows)
+    }
+
+    fn part1(spring_rows: &Self::Input) -> Answer {
+        let sum_combinations: u32 = parallel::map(spring_rows, |spring_row| {
+            spring_row.count_combinations()
+        }).iter().sum();
+
+        sum_combinations.into()
+    }
+
+    fn part2(_spring_rows: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SpringRow {
+    original_line: String,
+    corrupt_spring_pattern: Vec<u32>
+}
+
+impl SpringRow {
+    fn from(line: &str) -> Result<Self> {
+        let (springs, pattern) = input::split_pair(line, " ")?;
+
+        if let Some((_, spring)) = input::chars(springs).find(|(c, _)| !".#?".contains(*c)) {
+            return Err(Error::parse(spring, "expected '.', '#' or '?'"));
+        }
+        let original_line = springs.to_string();
+        let corrupt_spring_pattern = parse::all(pattern, |p| p.separated(",", Parser::integer::<u32>))?;
+
+        Ok(SpringRow {original_line, corrupt_spring_pattern })
+    }
+
+    fn count_combinations(&self) -> u32 {
+        let starting_pattern = self.original_line.clone();
+
+        let mut combinations = 0;
+        self.explore_combinations(starting_pattern, &mut combinations);
+
+        combinations
+    }
+
+    fn explore_combinations(&self, current: String, combinations: &mut u32) {
+        // if there are no place holders me might find a valid combination
+        if !current.contains("?") {
+            if self.is_valid(&current) {
+                *combinations = *combinations + 1u32;
+            }
+
+            return;
+        }
+
+        let new_corrupt_line = current.replacen("?", "#", 1);
+        self.explore_combinations(new_corrupt_line, combinations);
+
+        let new_working_line = current.replacen("?", ".", 1);
+        self.explore_combinations(new_working_line, combinations);
+    }
+
+    fn is_valid(&self, current: &String) -> bool {
+        let current_pattern = Self::extract_pattern(current);
+        if current_pattern.len() != self.corrupt_spring_pattern.len() {
+            return false;
+        }
+
+        for i in 0 .. self.corrupt_spring_pattern.len() {
+            if current_pattern[i] != self.corrupt_spring_pattern[i] {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn extract_pattern(current: &String) -> Vec<u32> {
+        let pattern: Vec<u32> = current
+            .replace("?", " ")
+            .replace(".", " ")
+            .split_whitespace()
+            .map(|item| {
+                item.len() as u32
+            })
+            .collect();
+
+        pattern
+    }
+}
+
+impl fmt::Display for SpringRow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pattern: Vec<String> = self.corrupt_spring_pattern.iter().map(|n| n.to_string()).collect();
+
+        write!(f, "{} {}", self.original_line, pattern.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{examples, Solution};
+    use proptest::prelude::*;
+
+    use super::{Day12, SpringRow};
+
+    #[test]
+    fn test_examples() {
+        examples::check(&Day12, env!("CARGO_MANIFEST_DIR"));
+    }
+
+    fn spring_row() -> impl Strategy<Value = SpringRow> {
+        ("[.#?]{1,20}", prop::collection::vec(1..10u32, 1..6)).prop_map(|(original_line, corrupt_spring_pattern)|
+            SpringRow { original_line, corrupt_spring_pattern }
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(rows in prop::collection::vec(spring_row(), 1..10)) {
+            let printed: Vec<String> = rows.iter().map(|row| row.to_string()).collect();
+
+            prop_assert_eq!(Day12::parse(&printed.join("\n")).unwrap(), rows);
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use aoc_common::generate::Generator;
+use aoc_common::random::Rng;
+use aoc_common::{Answer, Part};
+
+use crate::Day13;
+
+type Pattern = Vec<Vec<bool>>;
+
+// a reflection line between row (or column) index and index + 1
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Line {
+    horizontal: bool,
+    index: usize
+}
+
+impl Line {
+    fn score(&self) -> usize {
+        if self.horizontal { (self.index + 1) * 100 } else { self.index + 1 }
+    }
+}
+
+fn transpose(pattern: &Pattern) -> Pattern {
+    (0..pattern[0].len()).map(|x| pattern.iter().map(|row| row[x]).collect()).collect()
+}
+
+// rows mirrored at the line between row and row + 1, as far as the pattern goes
+fn mirrored_rows(height: usize, row: usize) -> impl Iterator<Item = (usize, usize)> {
+    (0..=row).rev().zip(row + 1..height)
+}
+
+// every line at which the whole pattern is mirrored
+fn perfect_lines(pattern: &Pattern) -> Vec<Line> {
+    let columns = transpose(pattern);
+    let horizontal = (0..pattern.len() - 1)
+        .filter(|&r| mirrored_rows(pattern.len(), r).all(|(a, b)| pattern[a] == pattern[b]))
+        .map(|index| Line { horizontal: true, index });
+    let vertical = (0..columns.len() - 1)
+        .filter(|&c| mirrored_rows(columns.len(), c).all(|(a, b)| columns[a] == columns[b]))
+        .map(|index| Line { horizontal: false, index });
+
+    horizontal.chain(vertical).collect()
+}
+
+// every new line which shows up when a single cell is flipped
+fn smudged_lines(pattern: &Pattern) -> Vec<Line> {
+    let original = perfect_lines(pattern);
+    let mut lines: Vec<Line> = Vec::new();
+
+    for y in 0..pattern.len() {
+        for x in 0..pattern[0].len() {
+            let mut flipped = pattern.clone();
+            flipped[y][x] = !flipped[y][x];
+
+            for line in perfect_lines(&flipped) {
+                if !original.contains(&line) && !lines.contains(&line) {
+                    lines.push(line);
+                }
+            }
+        }
+    }
+
+    lines
+}
+
+// mirror the left side of every row onto the right side at the line after column
+fn mirror_columns(pattern: &mut Pattern, column: usize) {
+    for row in pattern.iter_mut() {
+        for (a, b) in mirrored_rows(row.len(), column) {
+            row[b] = row[a];
+        }
+    }
+}
+
+fn mirror_rows(pattern: &mut Pattern, row: usize) {
+    for (a, b) in mirrored_rows(pattern.len(), row) {
+        pattern[b] = pattern[a].clone();
+    }
+}
+
+// a pattern with a clean vertical reflection and a horizontal one which is off by one
+// cell. Transposed half of the time, so that both kinds of lines come up in both parts
+fn pattern(rng: &mut Rng) -> Pattern {
+    loop {
+        let width = rng.range(5..16) as usize;
+        let height = rng.range(5..16) as usize;
+        let mut pattern: Pattern = (0..height).map(|_| (0..width).map(|_| rng.chance(0.5)).collect()).collect();
+
+        let column = rng.below(width - 1);
+        let row = rng.below(height - 1);
+        mirror_columns(&mut pattern, column);
+        mirror_rows(&mut pattern, row);
+
+        // the smudge has to be outside of the columns covered by the vertical reflection,
+        // otherwise it would break that one as well
+        let outside: Vec<usize> = (0..width).filter(|&x| mirrored_rows(width, column).all(|(a, b)| a != x && b != x)).collect();
+        let inside: Vec<usize> = mirrored_rows(height, row).flat_map(|(a, b)| [a, b]).collect();
+        if outside.is_empty() {
+            continue;
+        }
+        let (x, y) = (*rng.pick(&outside), *rng.pick(&inside));
+        pattern[y][x] = !pattern[y][x];
+
+        if rng.chance(0.5) {
+            pattern = transpose(&pattern);
+        }
+
+        // the random part may have brought in further reflections by chance
+        if perfect_lines(&pattern).len() == 1 && smudged_lines(&pattern).len() == 1 {
+            return pattern;
+        }
+    }
+}
+
+impl Generator for Day13 {
+    // size patterns of 5 to 15 rows and columns
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let patterns: Vec<String> = (0..size.max(1)).map(|_| {
+            pattern(rng).iter()
+                .map(|row| row.iter().map(|&rock| if rock { '#' } else { '.' }).collect::<String>() + "\n")
+                .collect()
+        }).collect();
+
+        patterns.join("\n")
+    }
+
+    // all reflection lines are checked, and for part 2 every single cell is flipped
+    fn reference(input: &str, part: Part) -> Answer {
+        let patterns: Vec<Pattern> = input.trim().split("\n\n")
+            .map(|block| block.lines().map(|line| line.chars().map(|c| c == '#').collect()).collect())
+            .collect();
+
+        let score: usize = patterns.iter().map(|pattern| {
+            let lines = match part {
+                Part::One => perfect_lines(pattern),
+                Part::Two => smudged_lines(pattern)
+            };
+
+            lines.first().map(|line| line.score()).unwrap_or(0)
+        }).sum();
+
+        score.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate;
+
+    use crate::Day13;
+
+    #[test]
+    fn test_generated() {
+        generate::check(&Day13, 20, 5);
+    }
+}
@@ -0,0 +1,45 @@
+mod generate;
+mod mirror;
+
+use aoc_common::{Answer, Result, Solution};
+use mirror::map::Map;
+use crate::mirror::map::{parse_input, summarize_all};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Point of Incidence";
+
+    type Input = Vec<Map>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(maps: &Self::Input) -> Answer {
+        match summarize_all(maps, 0) {
+            Some(total) => total.into(),
+            None => Answer::Unsolved
+        }
+    }
+
+    fn part2(maps: &Self::Input) -> Answer {
+        match summarize_all(maps, 1) {
+            Some(total) => total.into(),
+            None => Answer::Unsolved
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::examples;
+
+    use super::Day13;
+
+    #[test]
+    fn test_examples() {
+        examples::check(&Day13, env!("CARGO_MANIFEST_DIR"));
+    }
+}
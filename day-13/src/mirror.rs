@@ -0,0 +1,213 @@
+pub mod map {
+    use std::fmt;
+
+    use aoc_common::{input, Error, Grid, Result};
+
+    // None if one of the maps has no reflection line
+    pub fn summarize_all(maps: &Vec<Map>, smudges: u32) -> Option<u32> {
+        maps.iter().map(|m|
+            m.summarize(smudges)
+        ).sum()
+    }
+
+    pub fn parse_input(input: &str) -> Result<Vec<Map>> {
+        let lines = input::lines(input);
+        if lines.is_empty() {
+            return Err(Error::parse(&input[input.len()..], "expected a map"));
+        }
+
+        // two empty lines in a row leave an empty map between them
+        if let Some(pair) = lines.windows(2).find(|pair| pair[0].is_empty() && pair[1].is_empty()) {
+            return Err(Error::parse(pair[1], "expected a map"));
+        }
+
+        let maps: Vec<&[&str]> = input::paragraphs(&lines);
+
+        maps.iter().map(|&str_map|
+            Map::from(str_map)
+        ).collect()
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct Map {
+        data: Grid<Type>
+    }
+
+    impl Map {
+        pub fn from(lines: &[&str]) -> Result<Self> {
+            let data = Grid::from_lines(lines, |_, c| Type::from(c))?;
+
+            Ok(Map { data })
+        }
+
+        // smudges is the exact number of differing fields the reflection must have,
+        // 0 for a clean mirror. None if there is no such reflection line
+        pub fn summarize(&self, smudges: u32) -> Option<u32> {
+            // for now, we only try horizontal
+
+            if let Some(s) = Self::find_reflection_line(&self.data, smudges) {
+                return Some((s as u32 + 1) * 100) // horizontal line
+            }
+
+            // we have no horizontal line, try vertical. To keep our beautiful logic, we transpose
+            // our data and then do the search again
+            let data = self.data.transpose();
+            if let Some(s) = Self::find_reflection_line(&data, smudges) {
+                return Some(s as u32 + 1) // vertical line
+            }
+
+            None
+        }
+
+        // Find a horizontal reflection line.
+        // we count for each "mirrored" pair the amount of smudges
+        // if the smudge count is exactly the expected one, we found it!
+        //
+        // the last row has nothing below it, so it can never be a reflection line
+        fn find_reflection_line(map: &Grid<Type>, smudges: u32) -> Option<usize> {
+            for r in 0 .. map.height().saturating_sub(1) {
+                if Self::total_smudge_count(map, r as i32) == smudges {
+                    return Some(r);
+                }
+            }
+
+            None
+        }
+
+        fn total_smudge_count(map: &Grid<Type>, row: i32) -> u32 {
+            // maximal numbers of rows we could try before we reach one of the borders
+            let max_matching_rows = (row + 1).min(map.height() as i32 - row - 1);
+
+            // there is nothing to match against
+            if max_matching_rows == 0 {
+                return 0u32;
+            }
+
+            let mut total_smudge = 0u32;
+            for offset in 0 .. max_matching_rows {
+                let t_row = row - offset;
+                let b_row = row + 1 + offset;
+
+                total_smudge = total_smudge + Self::smudge_count(map, t_row, b_row);
+            }
+
+            total_smudge
+        }
+
+        fn smudge_count(map: &Grid<Type>, top_row: i32, bottom_row: i32) -> u32 {
+            if top_row < 0 || bottom_row >= (map.height() as i32) {
+                return 0;
+            }
+
+            let t_row: &[Type] = map.row(top_row as usize);
+            let b_row: &[Type] = map.row(bottom_row as usize);
+
+            // compare pair wise the values. if they differ, increase the counter
+            let mut smudge = 0u32;
+
+            for i in 0 .. t_row.len() {
+                if t_row.get(i).unwrap() != b_row.get(i).unwrap() {
+                    smudge = smudge + 1;
+                }
+            }
+
+            smudge
+        }
+    }
+
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    enum Type {
+        Ash,
+        Rock
+    }
+
+    impl Type {
+        fn from(c: &str) -> Result<Self> {
+            match c {
+                "." => Ok(Self::Ash),
+                "#" => Ok(Self::Rock),
+                _ => Err(Error::parse(c, "unknown type"))
+            }
+        }
+    }
+
+    impl fmt::Display for Map {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", self.data)
+        }
+    }
+
+    impl fmt::Display for Type {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Self::Ash => write!(f, "."),
+                Self::Rock => write!(f, "#")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::input;
+    use proptest::prelude::*;
+
+    use crate::mirror::map::{parse_input, summarize_all};
+
+    #[test]
+    fn test_part1() -> Result<(), String> {
+        let location = String::from("fixtures/example.txt");
+        let input = input::read_input(&location).map_err(|e| e.to_string())?;
+        let m = parse_input(&input).map_err(|e| e.to_string())?;
+        let r = summarize_all(&m, 0);
+
+        assert_eq!(r, Some(405));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<(), String> {
+        let location = String::from("fixtures/example.txt");
+        let input = input::read_input(&location).map_err(|e| e.to_string())?;
+        let m = parse_input(&input).map_err(|e| e.to_string())?;
+        let r = summarize_all(&m, 1);
+
+        assert_eq!(r, Some(400));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_odd_maps() {
+        assert_eq!(parse_input("").unwrap_err().to_string(), "expected a map");
+        assert_eq!(parse_input("  \n\n").unwrap_err().to_string(), "expected a map");
+        let input = "#.\n\n\n\n.#";
+        assert_eq!(parse_input(input).unwrap_err().locate(input).to_string(), "3:1: expected a map");
+
+        // no row and no column mirrors its neighbour
+        let maps = parse_input("#.\n.#\n\n##\n..").unwrap();
+        assert_eq!(summarize_all(&maps, 0), None);
+        assert_eq!(maps[1].summarize(0), Some(1));
+        assert_eq!(summarize_all(&parse_input("#").unwrap(), 1), None);
+    }
+
+    // the text of a few maps of ash and rocks
+    fn maps() -> impl Strategy<Value = String> {
+        let map = (1..10usize, 1..10usize).prop_flat_map(|(width, height)|
+            prop::collection::vec(prop::collection::vec(prop::sample::select(vec!['.', '#']), width), height)
+        ).prop_map(|rows| rows.iter().map(|row| row.iter().collect::<String>()).collect::<Vec<String>>().join("\n"));
+
+        prop::collection::vec(map, 1..5).prop_map(|maps| maps.join("\n\n"))
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(input in maps()) {
+            let maps = parse_input(&input).unwrap();
+            let printed: Vec<String> = maps.iter().map(|m| m.to_string()).collect();
+
+            prop_assert_eq!(parse_input(&printed.join("\n\n")).unwrap(), maps);
+        }
+    }
+}
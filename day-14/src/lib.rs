@@ -0,0 +1,59 @@
+mod mirror;
+mod visualize;
+
+use aoc_common::{Answer, Result, Solution};
+use crate::mirror::mirror::{parse_input, Map};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Parabolic Reflector Dish";
+
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        let mut map = map.clone();
+        map.tilt();
+
+        let w = map.weight();
+        w.into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        let mut map = map.clone();
+        map.all_spins();
+
+        let w = map.weight();
+        w.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{examples, Solution};
+    use proptest::prelude::*;
+
+    use super::Day14;
+
+    #[test]
+    fn test_examples() {
+        examples::check(&Day14, env!("CARGO_MANIFEST_DIR"));
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(input in (1..12usize, 1..12usize).prop_flat_map(|(width, height)|
+            prop::collection::vec(prop::collection::vec(prop::sample::select(vec!['.', 'O', '#']), width), height)
+        )) {
+            let lines: Vec<String> = input.iter().map(|row| row.iter().collect()).collect();
+            let map = Day14::parse(&lines.join("\n")).unwrap();
+
+            prop_assert_eq!(Day14::parse(&map.to_string()).unwrap(), map);
+        }
+    }
+}
@@ -0,0 +1,164 @@
+pub mod mirror {
+    use std::fmt;
+    use std::ops::Range;
+    use aoc_common::cycle;
+    use aoc_common::{Error, Grid, Result};
+    use aoc_common::visualize::{Cell, Colour};
+
+    pub fn parse_input(input: &str) -> Result<Map> {
+        Map::from(input)
+    }
+
+    #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    pub struct Map {
+        data: Grid<Material>
+    }
+
+    impl Map {
+        pub fn from(input: &str) -> Result<Self> {
+            let d = Grid::parse(input, |_, c| Material::from(c))?;
+
+            // instead of tilting to north, we flipped the data and need
+            // to tilt to the east
+            Ok(Map { data: d.transpose() })
+        }
+
+        pub fn all_spins(&mut self) {
+            const SPINS: usize = 1000000000;
+
+            // the maps repeat after a while, which jumps over all remaining full cycles
+            *self = cycle::nth(self.clone(), |map| {
+                let mut map = map.clone();
+                map.spin();
+
+                map
+            }, SPINS);
+        }
+
+        pub fn spin(&mut self) {
+            self.tilt(); // to north
+
+            self.data = self.data.transpose();
+            self.tilt(); // to west
+
+            self.data = self.data.transpose().flip_horizontal();
+            self.tilt(); // to south
+
+            self.data = self.data.transpose().flip_horizontal().flip_vertical();
+            self.tilt(); // to east
+
+            // go back to original state
+            self.data = self.data.flip_horizontal().transpose();
+        }
+
+        pub fn tilt(&mut self) {
+            for y in 0..self.data.height() {
+                Self::tilt_line(self.data.row_mut(y));
+            }
+        }
+
+        fn tilt_line(line: &mut [Material]) {
+            let mut start = 0usize;
+
+            loop {
+                let (next_obstacle, rocks_between) = Self::next_obstacle(line, start);
+                Self::tilt_in_range(line, rocks_between, start..next_obstacle);
+                if next_obstacle == line.len() {
+                    break;
+                }
+                start = next_obstacle + 1;
+            }
+        }
+
+        fn tilt_in_range(line: &mut [Material], rocks: u32, range: Range<usize>) {
+            // there is for sure a rusty way of doing the same
+            for i in range.start..range.start + rocks as usize {
+                line[i] = Material::RoundedRock;
+            }
+
+            for i in range.start + rocks as usize .. range.end {
+                line[i] = Material::Nothing;
+            }
+        }
+
+        fn next_obstacle(line: &[Material], start_at: usize) -> (usize, u32) {
+            let mut num_rounded_rock = 0u32;
+
+            for r in start_at..line.len() {
+                if *line.get(r).unwrap() == Material::RoundedRock {
+                    num_rounded_rock = num_rounded_rock + 1;
+                }
+
+                if *line.get(r).unwrap() == Material::CubicRock {
+                    return (r, num_rounded_rock);
+                }
+            }
+
+            // if we didn't found an obstacle, we report the EOL
+            (line.len(), num_rounded_rock)
+        }
+
+        pub fn weight(&self) -> u32 {
+             self.data.rows().map(|line|
+                Self::weight_line(line)
+            ).sum()
+        }
+
+        fn weight_line(line: &[Material]) -> u32 {
+            let mut weight = 0u32;
+
+            for (index, item) in line.iter().enumerate() {
+                if *item == Material::RoundedRock {
+                    weight = weight + (line.len() - index) as u32;
+                }
+            }
+
+            weight
+        }
+
+        // the rocks with north up, for --visualize
+        pub fn cells(&self) -> Grid<Cell> {
+            self.data.transpose().map(|material| match material {
+                Material::Nothing => Cell::coloured('·', Colour::Grey),
+                Material::RoundedRock => Cell::coloured('●', Colour::Yellow),
+                Material::CubicRock => Cell::coloured('█', Colour::Blue)
+            })
+        }
+    }
+
+
+    #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+    enum Material {
+        Nothing,
+        RoundedRock,
+        CubicRock
+    }
+
+    impl Material {
+        fn from(c: &str) -> Result<Self> {
+            match c {
+                "." => Ok(Self::Nothing),
+                "O" => Ok(Self::RoundedRock),
+                "#" => Ok(Self::CubicRock),
+                _ => Err(Error::parse(c, "unknown material"))
+            }
+        }
+    }
+
+    // north is up again, like in the input
+    impl fmt::Display for Map {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", self.data.transpose())
+        }
+    }
+
+    impl fmt::Display for Material {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Self::Nothing => write!(f, "."),
+                Self::RoundedRock => write!(f, "O"),
+                Self::CubicRock => write!(f, "#")
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,40 @@
+use aoc_common::visualize::{Frame, Visualize};
+use aoc_common::Part;
+
+use crate::mirror::mirror::Map;
+use crate::Day14;
+
+impl Visualize for Day14 {
+    // part 1 tilts once, part 2 shows every spin until the rocks are back in a state
+    // they had before, followed by the state after all the spins
+    fn frames(map: &Map, part: Part) -> Vec<Frame> {
+        let mut frames = vec![Frame::new(format!("before tilting, load {}", map.weight()), map.cells())];
+
+        match part {
+            Part::One => {
+                let mut map = map.clone();
+                map.tilt();
+                frames.push(Frame::new(format!("tilted north, load {}", map.weight()), map.cells()));
+            },
+            Part::Two => {
+                let mut seen: Vec<Map> = vec![map.clone()];
+                let mut current = map.clone();
+                loop {
+                    current.spin();
+                    if seen.contains(&current) {
+                        break;
+                    }
+
+                    frames.push(Frame::new(format!("spin {}, load {}", seen.len(), current.weight()), current.cells()));
+                    seen.push(current.clone());
+                }
+
+                let mut map = map.clone();
+                map.all_spins();
+                frames.push(Frame::new(format!("after 1000000000 spins, load {}", map.weight()), map.cells()));
+            }
+        }
+
+        frames
+    }
+}
@@ -0,0 +1,233 @@
+use std::collections::HashMap;
+use std::fmt;
+use aoc_common::parse;
+use aoc_common::trace::{self, Level};
+use aoc_common::{input, Answer, Error, Result, Solution};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Lens Library";
+
+    type Input = Vec<Step>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines = input::lines(input);
+        let Some(&line) = lines.get(0) else {
+            return Err(Error::parse(input, "expected a line of steps"));
+        };
+
+        parse::all(line, |p| p.separated(",", |p|
+            Step::from(p.take_while(|c| c != ','))
+        ))
+    }
+
+    fn part1(elements: &Self::Input) -> Answer {
+        let sum: u128 = elements.iter().map(|element| {
+            let hash = to_hash(&element.text);
+            trace::explain(Level::Steps, || format!("{} hashes to {}", element.text, hash));
+
+            hash
+        }).sum();
+
+        sum.into()
+    }
+
+    fn part2(elements: &Self::Input) -> Answer {
+        let mut boxes: HashMap<u128, Box> = HashMap::new();
+        elements.iter().for_each(|item| {
+            let operation = &item.operation;
+            let box_id = match &operation {
+                Operation::New(label, _) => to_hash(label.as_str()),
+                Operation::Remove(label) => to_hash(label.as_str())
+            };
+
+            boxes.entry(box_id).or_insert_with(|| Box::new(box_id));
+            let box_item = boxes.get_mut(&box_id).unwrap();
+
+            match operation {
+                Operation::Remove(label) => {
+                    box_item.remove(label.as_str());
+                },
+                Operation::New(label, value) => {
+                    let lens = Lens { label: String::from(label), value: value.clone() };
+                    box_item.add_or_replace(lens);
+                }
+            }
+
+            trace::explain(Level::Steps, || format!("after \"{}\": {}", item.text, boxes[&box_id]));
+            if trace::enabled(Level::Details) {
+                let mut ids: Vec<&u128> = boxes.keys().filter(|id| !boxes[id].lenses.is_empty()).collect();
+                ids.sort();
+                for id in ids {
+                    trace::explain(Level::Details, || format!("  {}", boxes[id]));
+                }
+            }
+        });
+
+        let result: u128 = boxes.iter().map(|box_item| {
+            let sum: u128 = box_item.1.focal_power().iter().sum();
+
+            sum
+        }).sum();
+
+        result.into()
+    }
+}
+
+fn to_hash(element: &str) -> u128 {
+    element.chars().fold(0u128, |agg, item|
+        ((agg + item as u128) * 17) % 256
+    )
+}
+
+struct Box {
+    id: u128,
+    lenses: Vec<Lens>
+}
+
+impl Box {
+    fn new(id: u128) -> Self {
+        let lenses: Vec<Lens> = Vec::new();
+
+        Box { id, lenses }
+    }
+
+    fn remove(&mut self, label: &str) {
+        if let Some(pos) = self.lenses.iter().position(|lens|
+            lens.label.as_str() == label
+        ) {
+            self.lenses.remove(pos);
+        }
+    }
+
+    fn add_or_replace(&mut self, lens: Lens) {
+        if let Some(pos) = self.lenses.iter().position(|item|
+            item.label == lens.label
+        ) {
+            self.lenses[pos] = lens;
+        } else {
+            self.lenses.push(lens);
+        }
+    }
+
+    fn focal_power(&self) -> Vec<u128> {
+        let mut power_values: Vec<u128> = Vec::new();
+
+        for (index, item) in self.lenses.iter().enumerate() {
+            let power = (self.id + 1) * (index as u128 + 1) * item.value;
+            power_values.push(power);
+        }
+
+        power_values
+    }
+}
+
+// like in the puzzle text, e.g. Box 3: [ot 7] [ab 5] [pc 6]
+impl fmt::Display for Box {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Box {}:", self.id)?;
+        for lens in self.lenses.iter() {
+            write!(f, " [{} {}]", lens.label, lens.value)?;
+        }
+
+        Ok(())
+    }
+}
+
+struct Lens {
+    label: String,
+    value: u128
+}
+
+// a step of the initialization sequence. The text is kept, because the hash of part 1
+// is taken over it as it was written
+#[derive(Debug, PartialEq)]
+pub struct Step {
+    text: String,
+    operation: Operation
+}
+
+impl Step {
+    fn from(item: &str) -> Result<Self> {
+        Ok(Step { text: item.to_string(), operation: Operation::from(item)? })
+    }
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Operation {
+    Remove(String),
+    New(String, u128)
+}
+
+impl Operation {
+    fn from(item: &str) -> Result<Self> {
+        if let Some(label) = item.strip_suffix("-") {
+            return Ok(Operation::Remove(label.to_string()));
+        }
+
+        let Some((label, value)) = item.split_once("=") else {
+            return Err(Error::parse(item, "expected a step like 'rn=1' or 'cm-'"));
+        };
+        // kept to a u32, so that the focusing power of every lens fits into the u128 sums
+        let value = input::number::<u32>(value)? as u128;
+
+        Ok(Operation::New(label.to_string(), value))
+    }
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operation::Remove(label) => write!(f, "{}-", label),
+            Operation::New(label, value) => write!(f, "{}={}", label, value)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{examples, Answer, Solution};
+    use proptest::prelude::*;
+
+    use super::{Day15, Operation, Step};
+
+    #[test]
+    fn test_examples() {
+        examples::check(&Day15, env!("CARGO_MANIFEST_DIR"));
+    }
+
+    fn step() -> impl Strategy<Value = Step> {
+        let operation = prop_oneof![
+            "[a-z]{1,6}".prop_map(Operation::Remove),
+            ("[a-z]{1,6}", 1..10u128).prop_map(|(label, value)| Operation::New(label, value))
+        ];
+
+        operation.prop_map(|operation| Step { text: operation.to_string(), operation })
+    }
+
+    #[test]
+    fn test_large_focal_lengths() {
+        let steps = Day15::parse("rn=4294967295,cm=4294967295").unwrap();
+        assert_eq!(Day15::part2(&steps), Answer::from(3 * 4294967295u64));
+
+        let e = Day15::parse("rn=4294967296").unwrap_err();
+        assert_eq!(e.locate("rn=4294967296").to_string(), "1:4: expected a number at '4294967296'");
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(steps in prop::collection::vec(step(), 1..20)) {
+            let printed: Vec<String> = steps.iter().map(|step| step.to_string()).collect();
+
+            prop_assert_eq!(Day15::parse(&printed.join(",")).unwrap(), steps);
+        }
+    }
+}
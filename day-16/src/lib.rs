@@ -0,0 +1,268 @@
+mod picture;
+mod query;
+mod visualize;
+
+use std::collections::HashSet;
+use std::fmt;
+use aoc_common::{parallel, Answer, Direction, Error, Grid, Point, Result, Solution};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "The Floor Will Be Lava";
+
+    type Input = Room;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Room::from(input)
+    }
+
+    fn part1(original: &Self::Input) -> Answer {
+        let mut r = original.clone();
+        r.simulate_beam(Point::new(-1, 0), Direction::Right);
+
+        let spots = r.num_energized_spots();
+        spots.into()
+    }
+
+    fn part2(original: &Self::Input) -> Answer {
+        let max_energy = original.best_entry().map_or(0, |(_, energized)| energized);
+
+        max_energy.into()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Room {
+    seen_beams: HashSet<Beam>,
+    mirrors: Grid<Option<Mirror>>
+}
+
+impl Room {
+    fn from(input: &str) -> Result<Self> {
+        let seen_beams: HashSet<Beam> = HashSet::new();
+        let mirrors = Grid::parse(input, |item, token| match item {
+            '.' => Ok(None),
+            _ => Mirror::try_from(item).map(Some).map_err(|e| Error::parse(token, e))
+        })?;
+
+        Ok(Room { seen_beams, mirrors })
+    }
+
+    // every spot next to the room, with the direction into it
+    fn entries(&self) -> Vec<(Point<i64>, Direction)> {
+        let width = self.mirrors.width() as i64;
+        let height = self.mirrors.height() as i64;
+
+        let mut entries: Vec<(Point<i64>, Direction)> = Vec::new();
+        for row in 0..height {
+            entries.push((Point::new(-1, row), Direction::Right));
+            entries.push((Point::new(width, row), Direction::Left));
+        }
+        for column in 0..width {
+            entries.push((Point::new(column, -1), Direction::Down));
+            entries.push((Point::new(column, height), Direction::Up));
+        }
+
+        entries
+    }
+
+    // a copy of the room after the beam came in at start
+    fn energize(&self, start: Point<i64>, direction: Direction) -> Room {
+        let mut room = self.clone();
+        room.simulate_beam(start, direction);
+
+        room
+    }
+
+    // the entry which energizes the most tiles, the one part 2 is looking for, and how many
+    // tiles it energizes. Every entry gets a room of its own, so they are simulated at the same time
+    fn best_entry(&self) -> Option<((Point<i64>, Direction), u32)> {
+        let entries = self.entries();
+        let energies = parallel::map(&entries, |&(start, direction)|
+            self.energize(start, direction).num_energized_spots()
+        );
+
+        (0..entries.len()).max_by_key(|&i| energies[i]).map(|i| (entries[i], energies[i]))
+    }
+
+    fn simulate_beam(&mut self, start: Point<i64>, direction: Direction) {
+        let initial_beam = Beam { position: start, direction };
+        let mut beams = vec![initial_beam];
+
+        loop {
+            let next_beams= self.simulate_beams(&beams);
+            if next_beams.len() == 0 {
+                break;
+            }
+            beams = next_beams;
+        }
+    }
+
+    fn simulate_beams(&mut self, beams: &Vec<Beam>) -> Vec<Beam> {
+        let mut next_beams: Vec<Beam> = Vec::new();
+        for beam in beams {
+            let next_pos = &beam.next_position();
+            let mirror = self.mirrors.point(*next_pos).and_then(|p|
+                self.mirrors[p].as_ref()
+            );
+            if let Some(hit_mirror) = mirror {
+                // there is a mirror at the next position, let them reflect
+                let reflected_beams = hit_mirror.reflect(beam, next_pos.clone());
+                next_beams.extend(reflected_beams);
+            } else {
+                // there is no mirror, just move the beam forward
+                next_beams.push(Beam { position: next_pos.clone(), direction: beam.direction.clone() });
+            }
+        }
+
+        let mut filtered_beams: Vec<Beam> = Vec::new();
+        for beam in next_beams {
+            if !self.seen_beams.contains(&beam) && self.mirrors.point(beam.position).is_some() {
+                self.seen_beams.insert(beam.clone());
+                filtered_beams.push(beam);
+            }
+        }
+
+        filtered_beams
+    }
+
+    fn num_energized_spots(&self) -> u32 {
+        let mut spots: HashSet<Point<i64>> = HashSet::new();
+        for beam in self.seen_beams.iter() {
+            spots.insert(beam.position.clone());
+        }
+
+        spots.len() as u32
+    }
+}
+
+#[derive(Eq, Hash, PartialEq, Copy, Clone, Debug)]
+struct Beam {
+    position: Point<i64>,
+    direction: Direction
+}
+
+impl Beam {
+    fn next_position(&self) -> Point<i64> {
+        self.position + self.direction.vector()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Mirror {
+    Vertical,
+    Horizontal,
+    BottomToTop,
+    TopToBottom
+}
+
+impl Mirror {
+    fn reflect(&self, beam: &Beam, next_pos: Point<i64>) -> Vec<Beam> {
+        let mut new_beams: Vec<Beam> = Vec::new();
+
+        match (beam.direction, self) {
+            (Direction::Right | Direction::Left, Mirror::Horizontal) => {
+                new_beams.push(Beam { direction: beam.direction.clone(), position: next_pos })
+            },
+            (Direction::Up | Direction::Down, Mirror::Vertical) => {
+                new_beams.push(Beam { direction: beam.direction.clone(), position: next_pos })
+            },
+            (Direction::Right | Direction::Left, Mirror::Vertical) => {
+                new_beams.push(Beam { position: next_pos.clone(), direction: Direction::Up });
+                new_beams.push(Beam { position: next_pos.clone(), direction: Direction::Down });
+            },
+            (Direction::Up | Direction::Down, Mirror::Horizontal) => {
+                new_beams.push(Beam { position: next_pos.clone(), direction: Direction::Right });
+                new_beams.push(Beam { position: next_pos.clone(), direction: Direction::Left });
+            },
+            (Direction::Right, Mirror::BottomToTop) => {
+                new_beams.push(Beam { position: next_pos, direction: Direction::Up});
+            },
+            (Direction::Left, Mirror::BottomToTop) => {
+                new_beams.push(Beam { position: next_pos, direction: Direction::Down});
+            },
+            (Direction::Up, Mirror::BottomToTop) => {
+                new_beams.push(Beam { position: next_pos, direction: Direction::Right});
+            },
+            (Direction::Down, Mirror::BottomToTop) => {
+                new_beams.push(Beam { position: next_pos, direction: Direction::Left});
+            },
+            (Direction::Right, Mirror::TopToBottom) => {
+                new_beams.push(Beam { position: next_pos, direction: Direction::Down});
+            },
+            (Direction::Left, Mirror::TopToBottom) => {
+                new_beams.push(Beam { position: next_pos, direction: Direction::Up});
+            },
+            (Direction::Up, Mirror::TopToBottom) => {
+                new_beams.push(Beam { position: next_pos, direction: Direction::Left});
+            },
+            (Direction::Down, Mirror::TopToBottom) => {
+                new_beams.push(Beam { position: next_pos, direction: Direction::Right});
+            }
+        }
+
+        new_beams
+    }
+}
+
+impl TryFrom<char> for Mirror {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> std::result::Result<Self, Self::Error> {
+        match value {
+            '|' => Ok(Self::Vertical),
+            '-' => Ok(Self::Horizontal),
+            '/' => Ok(Self::BottomToTop),
+            '\\' => Ok(Self::TopToBottom),
+            _ => Err("Unknown value")
+        }
+    }
+}
+
+// the contraption as in the input, without any beams
+impl fmt::Display for Room {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tiles = self.mirrors.map(|mirror| match mirror {
+            Some(Mirror::Vertical) => '|',
+            Some(Mirror::Horizontal) => '-',
+            Some(Mirror::BottomToTop) => '/',
+            Some(Mirror::TopToBottom) => '\\',
+            None => '.'
+        });
+
+        write!(f, "{}", tiles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use aoc_common::{examples, Grid, Solution};
+    use proptest::prelude::*;
+
+    use super::{Day16, Mirror, Room};
+
+    #[test]
+    fn test_examples() {
+        examples::check(&Day16, env!("CARGO_MANIFEST_DIR"));
+    }
+
+    fn room() -> impl Strategy<Value = Room> {
+        let tiles = vec![None, None, Some(Mirror::Vertical), Some(Mirror::Horizontal), Some(Mirror::BottomToTop), Some(Mirror::TopToBottom)];
+
+        (1..12usize, 1..12usize).prop_flat_map(move |(width, height)|
+            prop::collection::vec(prop::sample::select(tiles.clone()), width * height)
+                .prop_map(move |cells| Room { seen_beams: HashSet::new(), mirrors: Grid::new(width, height, cells) })
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(room in room()) {
+            prop_assert_eq!(Day16::parse(&room.to_string()).unwrap(), room);
+        }
+    }
+}
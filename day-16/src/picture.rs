@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+
+use aoc_common::grid::Position;
+use aoc_common::image::{Draw, Image, Pixmap, Rgb};
+use aoc_common::{Direction, Part, Point};
+
+use crate::{Day16, Mirror, Room};
+
+// every tile is drawn as SCALE x SCALE pixels
+const SCALE: usize = 3;
+
+const MIRROR: Rgb = Rgb(120, 170, 255);
+
+// from cold to hot: how many beams, in different directions, went through a tile
+const HEAT: [Rgb; 5] = [Rgb(0, 0, 0), Rgb(120, 20, 20), Rgb(220, 80, 0), Rgb(255, 200, 0), Rgb(255, 255, 220)];
+
+// the pixels of a mirror within its tile
+fn mirror_pixels(mirror: &Mirror) -> [(usize, usize); 3] {
+    match mirror {
+        Mirror::Vertical => [(1, 0), (1, 1), (1, 2)],
+        Mirror::Horizontal => [(0, 1), (1, 1), (2, 1)],
+        Mirror::BottomToTop => [(0, 2), (1, 1), (2, 0)],
+        Mirror::TopToBottom => [(0, 0), (1, 1), (2, 2)]
+    }
+}
+
+impl Draw for Day16 {
+    // a heatmap of the energized tiles with the mirrors on top. Part 2 uses the entry
+    // which energizes the most tiles
+    fn draw(room: &Room, part: Part) -> Option<Image> {
+        let (start, direction) = match part {
+            Part::One => (Point::new(-1, 0), Direction::Right),
+            Part::Two => room.best_entry()?.0
+        };
+        let room = room.energize(start, direction);
+
+        let mut heat: HashMap<Position, usize> = HashMap::new();
+        for beam in room.seen_beams.iter() {
+            if let Some(position) = room.mirrors.point(beam.position) {
+                *heat.entry(position).or_default() += 1;
+            }
+        }
+
+        let mut pixmap = Pixmap::new(room.mirrors.width() * SCALE, room.mirrors.height() * SCALE, HEAT[0]);
+        for (position, mirror) in room.mirrors.iter() {
+            let (x, y) = (position.0 * SCALE, position.1 * SCALE);
+
+            let beams = heat.get(&position).copied().unwrap_or(0);
+            pixmap.fill(x, y, SCALE, HEAT[beams.min(HEAT.len() - 1)]);
+            if let Some(mirror) = mirror {
+                for (dx, dy) in mirror_pixels(mirror) {
+                    pixmap.set(x + dx, y + dy, MIRROR);
+                }
+            }
+        }
+
+        Some(Image::Ppm(pixmap))
+    }
+}
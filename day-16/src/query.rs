@@ -0,0 +1,61 @@
+use aoc_common::query::{Command, Query};
+use aoc_common::{input, Direction, Error, Point, Result};
+
+use crate::{Day16, Room};
+
+const BEAM: Command = Command { name: "beam", usage: "<x> <y> <right|left|up|down>", help: "the tiles a beam energizes which enters the room at x,y" };
+const BEST: Command = Command { name: "best", usage: "", help: "the entry which energizes the most tiles" };
+
+// the full name or the letter of a dig plan
+fn direction(token: &str) -> Result<Direction> {
+    match token {
+        "up" => Ok(Direction::Up),
+        "right" => Ok(Direction::Right),
+        "down" => Ok(Direction::Down),
+        "left" => Ok(Direction::Left),
+        _ => Direction::from_letter(&token.to_uppercase())
+            .map_err(|_| Error::parse(token, "expected one of right, left, up or down"))
+    }
+}
+
+fn name(direction: Direction) -> &'static str {
+    match direction {
+        Direction::Up => "up",
+        Direction::Right => "right",
+        Direction::Down => "down",
+        Direction::Left => "left"
+    }
+}
+
+impl Query for Day16 {
+    const COMMANDS: &'static [Command] = &[BEAM, BEST];
+
+    fn query(room: &Room, command: &str, arguments: &[&str]) -> Result<String> {
+        match command {
+            "beam" => {
+                let [x, y, d] = BEAM.arguments(arguments)?;
+                let tile = Point::new(input::number::<i64>(x)?, input::number::<i64>(y)?);
+                let direction = direction(d)?;
+                if room.mirrors.point(tile).is_none() {
+                    return Err(Error::Usage(format!("{} is not in the {}x{} room", tile, room.mirrors.width(), room.mirrors.height())));
+                }
+
+                // the beam comes from the tile before, like part 1 starts left of the room
+                let start = tile - direction.vector();
+                let energized = room.energize(start, direction).num_energized_spots();
+
+                Ok(format!("{} tiles are energized", energized))
+            },
+            "best" => {
+                let [] = BEST.arguments(arguments)?;
+                let Some(((start, direction), energized)) = room.best_entry() else {
+                    return Err(Error::Usage(String::from("the room is empty")));
+                };
+                let tile = start + direction.vector();
+
+                Ok(format!("beam {} {} {}: {} tiles are energized", tile.x, tile.y, name(direction), energized))
+            },
+            _ => Err(Error::Usage(format!("there is no command {}", command)))
+        }
+    }
+}
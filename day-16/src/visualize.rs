@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+use aoc_common::grid::Position;
+use aoc_common::visualize::{Cell, Colour, Frame, Visualize};
+use aoc_common::{Direction, Part, Point};
+
+use crate::{Day16, Mirror, Room};
+
+// the room after the beam came in at start, energized tiles in yellow. Empty tiles show
+// the direction of the beam going through them, or a cross if several do
+fn draw(original: &Room, start: Point<i64>, direction: Direction) -> Frame {
+    let room = original.energize(start, direction);
+
+    let mut directions: HashMap<Position, Vec<Direction>> = HashMap::new();
+    for beam in room.seen_beams.iter() {
+        if let Some(position) = room.mirrors.point(beam.position) {
+            directions.entry(position).or_default().push(beam.direction);
+        }
+    }
+
+    let mut cells = room.mirrors.map(|mirror| {
+        let symbol = match mirror {
+            Some(Mirror::Vertical) => '│',
+            Some(Mirror::Horizontal) => '─',
+            Some(Mirror::BottomToTop) => '╱',
+            Some(Mirror::TopToBottom) => '╲',
+            None => '·'
+        };
+
+        Cell::coloured(symbol, Colour::Grey)
+    });
+    for (&position, beams) in directions.iter() {
+        let symbol = match (&room.mirrors[position], &beams[..]) {
+            (None, [direction]) => direction.arrow(),
+            (None, _) => '┼',
+            (Some(_), _) => cells[position].symbol
+        };
+        cells[position] = Cell::coloured(symbol, Colour::Yellow);
+    }
+
+    let caption = format!("beam from ({}, {}) going {:?}, {} tiles energized", start.x, start.y, direction, room.num_energized_spots());
+
+    Frame::new(caption, cells)
+}
+
+impl Visualize for Day16 {
+    // part 2 shows the entry which energizes the most tiles
+    fn frames(room: &Room, part: Part) -> Vec<Frame> {
+        let (start, direction) = match part {
+            Part::One => (Point::new(-1, 0), Direction::Right),
+            Part::Two => {
+                let Some((best, _)) = room.best_entry() else { return Vec::new() };
+
+                best
+            }
+        };
+
+        vec![draw(room, start, direction)]
+    }
+}
@@ -0,0 +1,163 @@
+mod picture;
+
+use aoc_common::parse;
+use aoc_common::{input, Answer, Direction, Error, Point, Result, Solution};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const TITLE: &'static str = "Lavaduct Lagoon";
+
+    // every line of the dig plan, read once as written and once from the colour code
+    type Input = Vec<(DigAction, DigAction)>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines = input::lines(input);
+
+        lines.iter().map(|line| DigAction::from(line)).collect()
+    }
+
+    fn part1(actions: &Self::Input) -> Answer {
+        let plain: Vec<DigAction> = actions.iter().map(|a| a.0.clone()).collect();
+
+        dig_hole(&plain).into()
+    }
+
+    fn part2(actions: &Self::Input) -> Answer {
+        let colour: Vec<DigAction> = actions.iter().map(|a| a.1.clone()).collect();
+
+        dig_hole(&colour).into()
+    }
+}
+
+fn dig_hole(actions: &Vec<DigAction>) -> u128 {
+    let mut hole = Hole::new();
+    hole.process(actions);
+
+    hole.compute_interior()
+}
+
+struct Hole {
+    points: Vec<Point<i128>>
+}
+
+impl Hole {
+    fn new() -> Self {
+        let points: Vec<Point<i128>> = Vec::new();
+
+        Hole { points }
+    }
+
+    fn process(&mut self, actions: &Vec<DigAction>) {
+        let mut current_hole: Point<i128> = Point::new(0, 0);
+        self.points.push(current_hole);
+
+        for action in actions.iter() {
+            let next_hole = action.dig(&current_hole);
+
+            self.points.push(next_hole);
+            current_hole = next_hole;
+        }
+    }
+
+    fn compute_interior(&self) -> u128 {
+        let mut interior: i128 = 0;
+        let mut border_count: i128 = 0;
+        // formulae taken from https://www.mathopenref.com/coordpolygonarea.html
+
+        for point_pair in self.points.windows(2) {
+            let border_length = point_pair[0].manhattan(&point_pair[1]);
+            border_count = border_count + border_length;
+
+            interior = interior +
+                point_pair[0].x * point_pair[1].y - point_pair[0].y * point_pair[1].x
+        }
+        interior = interior + border_count;
+        interior = interior / 2;
+        interior = interior + 1;
+
+        interior.unsigned_abs()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DigAction {
+    direction: Direction,
+    amount: i128
+}
+
+impl DigAction {
+    fn from(line: &str) -> Result<(Self, Self)> {
+        // R 6 (#70c710)
+        parse::all(line, |p| {
+            let (_, letter) = p.char()?;
+            let direction = Direction::from_letter(letter)?;
+            p.expect(" ")?;
+            // kept to a u32, so that the corners and the area of the lagoon fit into an i128
+            let amount = p.integer::<u32>()? as i128;
+            let plain = DigAction { direction, amount };
+
+            p.expect(" ")?;
+            let code = p.bracketed("(#", ")", |p| Ok(p.take_while(|c| matches!(c, '0'..='9' | 'a'..='f'))))?;
+            if code.len() != 6 {
+                return Err(Error::parse(code, "expected a colour of six hex digits"));
+            }
+            let (amount_string, direction) = code.split_at(5);
+            let direction = Direction::from_hex_digit(direction)?;
+            let amount = i128::from_str_radix(amount_string, 16).unwrap();
+            let colour = DigAction { direction, amount };
+
+            Ok((plain, colour))
+        })
+    }
+
+    fn dig(&self, start: &Point<i128>) -> Point<i128> {
+        let new_hole = *start + self.direction.vector::<i128>() * self.amount;
+
+        new_hole
+    }
+}
+
+// the dig plan again, the colour code is made up of the amount and direction of the second action
+pub fn print(actions: &[(DigAction, DigAction)]) -> String {
+    let lines: Vec<String> = actions.iter().map(|(plain, colour)|
+        format!("{} {} (#{:05x}{})", plain.direction.letter(), plain.amount, colour.amount, colour.direction.hex_digit())
+    ).collect();
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{examples, Answer, Direction, Solution};
+    use proptest::prelude::*;
+
+    use super::{print, Day18, DigAction};
+
+    #[test]
+    fn test_examples() {
+        examples::check(&Day18, env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_large_amounts() {
+        let input = "R 4294967295 (#000000)\nD 4294967295 (#000000)\nL 4294967295 (#000000)\nU 4294967295 (#000000)";
+        let actions = Day18::parse(input).unwrap();
+        assert_eq!(Day18::part1(&actions), Answer::from(4294967296u128 * 4294967296));
+
+        let e = Day18::parse("R 4294967296 (#000000)").unwrap_err();
+        assert_eq!(e.locate("R 4294967296 (#000000)").to_string(), "1:3: expected a number at '4294967296'");
+    }
+
+    fn action(max: i128) -> impl Strategy<Value = DigAction> {
+        (prop::sample::select(Direction::ALL.to_vec()), 0..max).prop_map(|(direction, amount)| DigAction { direction, amount })
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(actions in prop::collection::vec((action(100), action(0x100000)), 1..20)) {
+            prop_assert_eq!(Day18::parse(&print(&actions)).unwrap(), actions);
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use aoc_common::image::{Draw, Image, Rgb, Svg};
+use aoc_common::Part;
+
+use crate::{Day18, DigAction, Hole};
+
+const LAGOON: Rgb = Rgb(173, 216, 230);
+const TRENCH: Rgb = Rgb(40, 40, 40);
+
+impl Draw for Day18 {
+    // the lagoon as a polygon around the dug out area. In part 1 every trench is drawn
+    // in the colour the dig plan gives for it
+    fn draw(actions: &Vec<(DigAction, DigAction)>, part: Part) -> Option<Image> {
+        let plan: Vec<DigAction> = actions.iter().map(|a| match part {
+            Part::One => a.0.clone(),
+            Part::Two => a.1.clone()
+        }).collect();
+
+        let mut hole = Hole::new();
+        hole.process(&plan);
+        let points: Vec<(f64, f64)> = hole.points.iter().map(|p| (p.x as f64, p.y as f64)).collect();
+
+        let min_x = points.iter().map(|p| p.0).fold(f64::MAX, f64::min);
+        let max_x = points.iter().map(|p| p.0).fold(f64::MIN, f64::max);
+        let min_y = points.iter().map(|p| p.1).fold(f64::MAX, f64::min);
+        let max_y = points.iter().map(|p| p.1).fold(f64::MIN, f64::max);
+        let margin = (max_x - min_x).max(max_y - min_y).max(1.) / 20.;
+
+        let mut svg = Svg::new(min_x - margin, min_y - margin, max_x - min_x + 2. * margin, max_y - min_y + 2. * margin);
+        svg.polygon(&points, LAGOON, TRENCH);
+        if part == Part::One {
+            for (i, (_, colour)) in actions.iter().enumerate() {
+                // the colour code is the amount and direction of the second reading
+                let code = (colour.amount as u32) << 4 | colour.direction.hex_digit().to_digit(16).unwrap();
+                svg.line(points[i], points[i + 1], Rgb::from_hex(code));
+            }
+        }
+
+        Some(Image::Svg(svg))
+    }
+}
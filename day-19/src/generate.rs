@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+
+use aoc_common::generate::Generator;
+use aoc_common::random::Rng;
+use aoc_common::{Answer, Part};
+
+use crate::Day19;
+
+const CATEGORIES: [char; 4] = ['x', 'm', 'a', 's'];
+
+// (category, comparator, number) of a rule, None for the fallback at the end
+type Condition = Option<(usize, char, u64)>;
+type Workflows = HashMap<String, Vec<(Condition, String)>>;
+
+fn name(rng: &mut Rng, taken: &[String]) -> String {
+    loop {
+        let length = rng.range(2..4) as usize;
+        let name: String = (0..length).map(|_| (b'a' + rng.below(26) as u8) as char).collect();
+        if name != "in" && !taken.contains(&name) {
+            return name;
+        }
+    }
+}
+
+fn parse(input: &str) -> (Workflows, Vec<[u64; 4]>) {
+    let (workflows, parts) = input.trim().split_once("\n\n").unwrap();
+
+    let workflows = workflows.lines().map(|line| {
+        let (name, rules) = line.trim_end_matches('}').split_once('{').unwrap();
+        let rules = rules.split(',').map(|rule| match rule.split_once(':') {
+            Some((condition, target)) => {
+                let category = CATEGORIES.iter().position(|&c| condition.starts_with(c)).unwrap();
+                let comparator = condition.chars().nth(1).unwrap();
+                let number = condition[2..].parse().unwrap();
+
+                (Some((category, comparator, number)), target.to_string())
+            },
+            None => (None, rule.to_string())
+        }).collect();
+
+        (name.to_string(), rules)
+    }).collect();
+
+    let parts = parts.lines().map(|line| {
+        let mut ratings = [0; 4];
+        for (i, rating) in line.trim_matches(|c| c == '{' || c == '}').split(',').enumerate() {
+            ratings[i] = rating[2..].parse().unwrap();
+        }
+
+        ratings
+    }).collect();
+
+    (workflows, parts)
+}
+
+fn accepted(workflows: &Workflows, ratings: &[u64; 4]) -> bool {
+    let mut current = "in";
+    loop {
+        match current {
+            "A" => return true,
+            "R" => return false,
+            _ => {}
+        }
+
+        current = workflows[current].iter().find(|(condition, _)| match condition {
+            Some((category, '<', number)) => ratings[*category] < *number,
+            Some((category, _, number)) => ratings[*category] > *number,
+            None => true
+        }).map(|(_, target)| target.as_str()).unwrap();
+    }
+}
+
+// Every number a rule compares against splits the ratings into ranges which all
+// workflows treat the same. Checking one rating per combination of ranges is enough
+fn combinations(workflows: &Workflows) -> u64 {
+    let mut bounds: Vec<Vec<u64>> = vec![vec![1, 4001]; 4];
+    for (condition, _) in workflows.values().flatten() {
+        match condition {
+            Some((category, '<', number)) => bounds[*category].push(*number),
+            Some((category, _, number)) => bounds[*category].push(*number + 1),
+            None => {}
+        }
+    }
+    for b in bounds.iter_mut() {
+        b.retain(|&n| (1..=4001).contains(&n));
+        b.sort();
+        b.dedup();
+    }
+
+    let mut count = 0;
+    for x in bounds[0].windows(2) {
+        for m in bounds[1].windows(2) {
+            for a in bounds[2].windows(2) {
+                for s in bounds[3].windows(2) {
+                    if accepted(workflows, &[x[0], m[0], a[0], s[0]]) {
+                        count = count + (x[1] - x[0]) * (m[1] - m[0]) * (a[1] - a[0]) * (s[1] - s[0]);
+                    }
+                }
+            }
+        }
+    }
+
+    count
+}
+
+impl Generator for Day19 {
+    // about size workflows which form a tree below "in", and twice as many parts
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let count = size.max(1);
+        let mut names: Vec<String> = vec![String::from("in")];
+        for _ in 1..count {
+            let name = name(rng, &names);
+            names.push(name);
+        }
+
+        // every workflow is sent to from one of the workflows before it, so there are no loops
+        let mut targets: Vec<Vec<String>> = vec![Vec::new(); count];
+        for (i, name) in names.iter().enumerate().skip(1) {
+            targets[rng.below(i)].push(name.clone());
+        }
+
+        let mut workflows: Vec<String> = Vec::new();
+        for (name, mut targets) in names.iter().zip(targets) {
+            for _ in 0..rng.range(1..4) {
+                targets.push(String::from(*rng.pick(&["A", "R"])));
+            }
+            rng.shuffle(&mut targets);
+
+            let fallback = targets.pop().unwrap();
+            let mut rules: Vec<String> = targets.iter().map(|target| {
+                let category = rng.pick(&CATEGORIES);
+                let comparator = rng.pick(&['<', '>']);
+
+                format!("{}{}{}:{}", category, comparator, rng.range(1..4001), target)
+            }).collect();
+            rules.push(fallback);
+
+            workflows.push(format!("{}{{{}}}", name, rules.join(",")));
+        }
+        rng.shuffle(&mut workflows);
+
+        let parts: Vec<String> = (0..count * 2).map(|_| {
+            let ratings: Vec<String> = CATEGORIES.iter().map(|c| format!("{}={}", c, rng.range(1..4001))).collect();
+
+            format!("{{{}}}", ratings.join(","))
+        }).collect();
+
+        format!("{}\n\n{}\n", workflows.join("\n"), parts.join("\n"))
+    }
+
+    // part 1 runs every part through the workflows, part 2 tries one rating out of
+    // every range the rules cut the ratings into
+    fn reference(input: &str, part: Part) -> Answer {
+        let (workflows, parts) = parse(input);
+
+        match part {
+            Part::One => parts.iter()
+                .filter(|ratings| accepted(&workflows, ratings))
+                .map(|ratings| ratings.iter().sum::<u64>())
+                .sum::<u64>()
+                .into(),
+            Part::Two => combinations(&workflows).into()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate;
+
+    use crate::Day19;
+
+    #[test]
+    fn test_generated() {
+        generate::check(&Day19, 50, 10);
+    }
+}
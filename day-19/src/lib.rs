@@ -0,0 +1,531 @@
+mod generate;
+mod query;
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use aoc_common::interval::Cuboid;
+use aoc_common::parse::{self, Parser};
+use aoc_common::trace::{self, Level};
+use aoc_common::{input, Answer, Error, Result, Solution};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const TITLE: &'static str = "Aplenty";
+
+    type Input = (DecisionMaker, Vec<Item>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines = input::lines(input);
+
+        let input_split = input::paragraphs(&lines);
+        if input_split.len() != 2 {
+            // point at the end of the input, where the parts are missing
+            let end = &input[input.len()..];
+            return Err(Error::parse(end, "expected workflows and parts separated by an empty line"));
+        }
+
+        let rules = input_split[0];
+        let items = input_split[1];
+
+        let items: Vec<Item> = items.iter().map(|item| Item::from(item)).collect::<Result<_>>()?;
+        let dm = DecisionMaker::from(rules)?;
+
+        Ok((dm, items))
+    }
+
+    fn part1((dm, items): &Self::Input) -> Answer {
+        let sum: u64 = items.iter().filter(|item| {
+            let accepted = dm.accept_item(item);
+            if trace::enabled(Level::Steps) {
+                dm.explain_item(item);
+            }
+
+            accepted
+        })
+            .map(|item| item.value())
+            .sum();
+
+        sum.into()
+    }
+
+    fn part2((dm, _): &Self::Input) -> Answer {
+        // every rating from 1 to 4000, as a box with an axis for each category
+        let all = Cuboid::new([1..4001, 1..4001, 1..4001, 1..4001]);
+
+        dm.accepted_volume(all, "in").into()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Item {
+    x: u32,
+    m: u32,
+    a: u32,
+    s: u32
+}
+
+impl Item {
+    fn from(line: &str) -> Result<Self> {
+        // {x=1679,m=44,a=2067,s=496}
+        parse::all(line, |p| p.bracketed("{", "}", |p| {
+            let x = p.field("x", "=", Parser::integer::<u32>)?;
+            p.expect(",")?;
+            let m = p.field("m", "=", Parser::integer::<u32>)?;
+            p.expect(",")?;
+            let a = p.field("a", "=", Parser::integer::<u32>)?;
+            p.expect(",")?;
+            let s = p.field("s", "=", Parser::integer::<u32>)?;
+
+            Ok(Item { x, m, a, s })
+        }))
+    }
+
+    fn value(&self) -> u64 {
+        return self.x as u64 + self.m as u64 + self.a as u64 + self.s as u64;
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DecisionMaker {
+    rules: HashMap<String, RuleSet>
+}
+
+impl DecisionMaker {
+    fn from(lines: &[&str]) -> Result<Self> {
+        // px{a<2006:qkq,m>2090:A,rfg}
+
+        let mut rules: HashMap<String, RuleSet> = HashMap::new();
+        // the name of every workflow with the targets of its rules, in the order of the lines
+        let mut workflows: Vec<(&str, Vec<&str>)> = Vec::new();
+
+        for line in lines {
+            let (rule_name, (rule_set, targets)) = parse::all(line, |p| {
+                let rule_name = p.word()?;
+                let rule_set = p.bracketed("{", "}", RuleSet::from)?;
+
+                Ok((rule_name, rule_set))
+            })?;
+
+            // the last rule has to catch every part which no other rule matches
+            let fallback = rule_set.rule_set.last().filter(|rule| rule.cmp.is_none());
+            if fallback.is_none() {
+                return Err(Error::parse(targets[targets.len() - 1], "expected a rule without a condition at the end"));
+            }
+
+            rules.insert(String::from(rule_name), rule_set);
+            workflows.push((rule_name, targets));
+        }
+
+        if !rules.contains_key("in") {
+            return Err(Error::parse(lines[0], "there is no workflow named 'in'"));
+        }
+
+        for (_, targets) in workflows.iter() {
+            if let Some(target) = targets.iter().find(|&&target| !Self::is_final(target) && !rules.contains_key(target)) {
+                return Err(Error::parse(target, "there is no workflow with this name"));
+            }
+        }
+
+        let targets: HashMap<&str, Vec<&str>> = workflows.iter().cloned().collect();
+        let mut finished: HashSet<&str> = HashSet::new();
+        for (name, _) in workflows.iter() {
+            if let Some(target) = Self::find_loop(name, &targets, &mut finished, &mut Vec::new()) {
+                return Err(Error::parse(target, "the workflows go round in circles"));
+            }
+        }
+
+        Ok(DecisionMaker { rules })
+    }
+
+    fn is_final(target: &str) -> bool {
+        target == "A" || target == "R"
+    }
+
+    // Walks the workflows depth first from name. A target which is still on the path leads
+    // back to where it came from, that target is returned. Workflows without a loop below
+    // them are finished and not walked again
+    fn find_loop<'a>(name: &'a str, targets: &HashMap<&'a str, Vec<&'a str>>, finished: &mut HashSet<&'a str>, path: &mut Vec<&'a str>) -> Option<&'a str> {
+        if finished.contains(name) {
+            return None;
+        }
+
+        path.push(name);
+        for &target in targets[name].iter().filter(|&&target| !Self::is_final(target)) {
+            if path.contains(&target) {
+                return Some(target);
+            }
+            if let Some(target) = Self::find_loop(target, targets, finished, path) {
+                return Some(target);
+            }
+        }
+        path.pop();
+        finished.insert(name);
+
+        None
+    }
+
+    // DecisionMaker::from makes sure that every target is a workflow, A or R, and that the
+    // workflows do not loop, so every part ends up in A or R
+    fn accept_item(&self, item: &Item) -> bool {
+        let mut current_rule = &self.rules["in"];
+        loop {
+            let next_rule = current_rule.evaluate(item);
+            if next_rule == "A" {
+                return true;
+            }
+            if next_rule == "R" {
+                return false;
+            }
+
+            current_rule = &self.rules[&next_rule];
+        }
+    }
+
+    // the workflows the item goes through, ending with A or R, and the rule which sent it on
+    // in every one of them. Missing workflows and loops are parse errors, see from
+    fn workflow_path(&self, item: &Item) -> (Vec<&str>, Vec<&Rule>) {
+        let mut path: Vec<&str> = vec!["in"];
+        let mut rules: Vec<&Rule> = Vec::new();
+        let mut name = "in";
+        while !Self::is_final(name) {
+            let rule = self.rules[name].matching_rule(item);
+            name = rule.next_rule.as_str();
+            rules.push(rule);
+            path.push(name);
+        }
+
+        (path, rules)
+    }
+
+    // how many of the parts in the box are accepted from the workflow on. Each rule splits the
+    // box it gets, the matching part goes on to the next workflow, the rest to the next rule
+    fn accepted_volume(&self, parts: Cuboid<4>, workflow: &str) -> u64 {
+        match workflow {
+            "A" => return parts.volume() as u64,
+            "R" => return 0,
+            _ => ()
+        }
+
+        let mut volume = 0;
+        let mut rest = Some(parts);
+        for rule in self.rules[workflow].rule_set.iter() {
+            let Some(parts) = rest else { break };
+
+            let (matching, left) = match &rule.cmp {
+                Some(cmp) => cmp.split(parts),
+                None => (Some(parts), None)
+            };
+            if let Some(matching) = matching {
+                volume = volume + self.accepted_volume(matching, &rule.next_rule);
+            }
+            rest = left;
+        }
+
+        volume
+    }
+
+    fn explain_item(&self, item: &Item) {
+        let (path, rules) = self.workflow_path(item);
+
+        trace::explain(Level::Steps, || format!("{}: {}", item, path.join(" -> ")));
+        for (name, rule) in path.iter().zip(rules) {
+            trace::explain(Level::Details, || format!("  {}: {}", name, rule));
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct RuleSet {
+    rule_set: Vec<Rule>
+}
+
+impl RuleSet {
+    // the rules together with their targets as they are in the text, for the checks of
+    // DecisionMaker::from
+    fn from<'a>(p: &mut Parser<'a>) -> Result<(Self, Vec<&'a str>)> {
+        let (rule_set, targets) = p.separated(",", Rule::from)?.into_iter().unzip();
+
+        Ok((RuleSet { rule_set }, targets))
+    }
+
+    fn evaluate(&self, item: &Item) -> String {
+        self.matching_rule(item).next_rule.clone()
+    }
+
+    // the last rule has no condition, DecisionMaker::from makes sure of it, so it matches
+    // every item which gets that far
+    fn matching_rule(&self, item: &Item) -> &Rule {
+        let (fallback, rules) = self.rule_set.split_last().expect("a workflow has at least one rule");
+
+        rules.iter().find(|rule| rule.evaluate(item)).unwrap_or(fallback)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Rule {
+    cmp: Option<Comparison>,
+    next_rule: String
+}
+
+impl Rule {
+    // the rule and the token of its target
+    fn from<'a>(p: &mut Parser<'a>) -> Result<(Self, &'a str)> {
+        // a<2006:qkq
+        let word = p.word()?;
+        if !matches!(p.peek(), Some('<' | '>')) {
+            // no comparison. Just the next rule
+
+            return Ok((Rule { cmp: None, next_rule: word.to_string() }, word))
+        }
+
+        let cmp = Some(Comparison::from(word, p)?);
+        p.expect(":")?;
+        let target = p.word()?;
+
+        Ok((Rule { cmp, next_rule: target.to_string() }, target))
+    }
+
+    fn evaluate(&self, item: &Item) -> bool {
+        return if let Some(cmp) = &self.cmp {
+            cmp.compare(item)
+        } else {
+            true // no comparison needed, is a just forward rule
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Comparison {
+    Less(Variable, u32),
+    Larger(Variable, u32)
+}
+
+impl Comparison {
+    // the comparator and the amount after the variable
+    fn from(variable: &str, p: &mut Parser) -> Result<Self> {
+        let variable = Variable::from(variable)?;
+        let (comparator, token) = p.char()?;
+        let amount = p.integer::<u32>()?;
+
+        match comparator {
+            '<' => Ok(Comparison::Less(variable, amount)),
+            '>' => Ok(Comparison::Larger(variable, amount)),
+            _ => Err(Error::parse(token, "unknown comparator"))
+        }
+    }
+
+    // the parts of the box which match and which do not
+    fn split(&self, parts: Cuboid<4>) -> (Option<Cuboid<4>>, Option<Cuboid<4>>) {
+        match self {
+            Self::Less(variable, number) => parts.split_at(variable.axis(), *number as i64),
+            Self::Larger(variable, number) => {
+                let (below, above) = parts.split_at(variable.axis(), *number as i64 + 1);
+
+                (above, below)
+            }
+        }
+    }
+
+    fn compare(&self, item: &Item) -> bool {
+        match self {
+            Self::Less(variable, number) => {
+                match variable {
+                    Variable::X => &item.x < number,
+                    Variable::M => &item.m < number,
+                    Variable::A => &item.a < number,
+                    Variable::S => &item.s < number
+                }
+            },
+            Self::Larger(variable, number) => {
+                match variable {
+                    Variable::X => &item.x > number,
+                    Variable::M => &item.m > number,
+                    Variable::A => &item.a > number,
+                    Variable::S => &item.s > number
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Variable {
+    X,
+    M,
+    A,
+    S
+}
+
+impl Variable {
+    fn from(v: &str) -> Result<Self> {
+        match v {
+            "x" => Ok(Self::X),
+            "m" => Ok(Self::M),
+            "a" => Ok(Self::A),
+            "s" => Ok(Self::S),
+            _ => Err(Error::parse(v, "unknown variable"))
+        }
+    }
+
+    fn axis(&self) -> usize {
+        match self {
+            Self::X => 0,
+            Self::M => 1,
+            Self::A => 2,
+            Self::S => 3
+        }
+    }
+}
+
+// the workflows, an empty line and the parts
+pub fn print(dm: &DecisionMaker, items: &[Item]) -> String {
+    let items: Vec<String> = items.iter().map(|item| item.to_string()).collect();
+
+    format!("{}\n\n{}", dm, items.join("\n"))
+}
+
+impl fmt::Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{x={},m={},a={},s={}}}", self.x, self.m, self.a, self.s)
+    }
+}
+
+// one workflow per line, sorted by name
+impl fmt::Display for DecisionMaker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut names: Vec<&String> = self.rules.keys().collect();
+        names.sort();
+
+        let workflows: Vec<String> = names.iter().map(|&name|
+            format!("{}{{{}}}", name, self.rules[name])
+        ).collect();
+
+        write!(f, "{}", workflows.join("\n"))
+    }
+}
+
+impl fmt::Display for RuleSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rules: Vec<String> = self.rule_set.iter().map(|rule| rule.to_string()).collect();
+
+        write!(f, "{}", rules.join(","))
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.cmp {
+            Some(cmp) => write!(f, "{}:{}", cmp, self.next_rule),
+            None => write!(f, "{}", self.next_rule)
+        }
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Less(variable, number) => write!(f, "{}<{}", variable, number),
+            Self::Larger(variable, number) => write!(f, "{}>{}", variable, number)
+        }
+    }
+}
+
+impl fmt::Display for Variable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::X => "x",
+            Self::M => "m",
+            Self::A => "a",
+            Self::S => "s"
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::trace::{self, Level};
+    use aoc_common::{examples, Answer, Solution};
+    use proptest::prelude::*;
+
+    use super::{print, Comparison, Day19, DecisionMaker, Item, Rule, RuleSet, Variable};
+
+    #[test]
+    fn test_examples() {
+        examples::check(&Day19, env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_explain() {
+        let input = Day19::parse("in{s<1351:px,qqz}\npx{a<2006:A,R}\nqqz{R}\n\n{x=1,m=2,a=3,s=4}").unwrap();
+        let (_, lines) = trace::record(Level::Details, || Day19::part1(&input));
+
+        assert_eq!(lines, vec!["{x=1,m=2,a=3,s=4}: in -> px -> A", "  in: s<1351:px", "  px: a<2006:A"]);
+    }
+
+    #[test]
+    fn test_large_ratings() {
+        let input = Day19::parse("in{A}\n\n{x=4294967295,m=4294967295,a=4294967295,s=4294967295}\n{x=4294967295,m=1,a=1,s=1}").unwrap();
+
+        assert_eq!(Day19::part1(&input), Answer::from(5 * 4294967295u64 + 3));
+    }
+
+    fn item() -> impl Strategy<Value = Item> {
+        (1..4001u32, 1..4001u32, 1..4001u32, 1..4001u32).prop_map(|(x, m, a, s)| Item { x, m, a, s })
+    }
+
+    #[test]
+    fn test_odd_workflows() {
+        let error = |input: &str| Day19::parse(input).unwrap_err().locate(input).to_string();
+
+        assert_eq!(error("in{a<5:px,R}\n\n{x=1,m=2,a=3,s=4}"), "1:8: there is no workflow with this name at 'px'");
+        assert_eq!(error("in{a<5:A}\n\n{x=1,m=2,a=3,s=4}"), "1:8: expected a rule without a condition at the end at 'A'");
+        assert_eq!(error("in{px}\npx{a<5:qs,R}\nqs{in}\n\n{x=1,m=2,a=3,s=4}"), "3:4: the workflows go round in circles at 'in'");
+        assert_eq!(error("in{A}\npx{px}\n\n{x=1,m=2,a=3,s=4}"), "2:4: the workflows go round in circles at 'px'");
+    }
+
+    // the rules of a workflow, which send the parts on to one of the targets
+    fn rule_set(targets: Vec<String>) -> impl Strategy<Value = RuleSet> {
+        let target = prop::sample::select(targets);
+        let variable = prop::sample::select(vec![Variable::X, Variable::M, Variable::A, Variable::S]);
+        let comparison = (variable, any::<bool>(), 1..4001u32).prop_map(|(variable, less, number)|
+            if less { Comparison::Less(variable, number) } else { Comparison::Larger(variable, number) }
+        );
+        let rule = (comparison, target.clone()).prop_map(|(cmp, next_rule)| Rule { cmp: Some(cmp), next_rule });
+
+        (prop::collection::vec(rule, 0..4), target).prop_map(|(mut rule_set, fallback)| {
+            rule_set.push(Rule { cmp: None, next_rule: fallback });
+
+            RuleSet { rule_set }
+        })
+    }
+
+    // workflows with random rules, starting with "in". A workflow only sends parts to the
+    // workflows after it, so they never loop
+    fn decision_maker() -> impl Strategy<Value = DecisionMaker> {
+        prop::collection::hash_set("[a-z]{2,3}", 0..6).prop_flat_map(|names| {
+            let mut names: Vec<String> = names.into_iter().filter(|name| name != "in").collect();
+            names.insert(0, String::from("in"));
+
+            let rule_sets: Vec<_> = (0..names.len()).map(|i| {
+                let targets = names[i + 1..].iter().cloned().chain([String::from("A"), String::from("R")]).collect();
+
+                rule_set(targets)
+            }).collect();
+
+            (Just(names), rule_sets)
+        }).prop_map(|(names, rule_sets)| DecisionMaker { rules: names.into_iter().zip(rule_sets).collect() })
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(dm in decision_maker(), items in prop::collection::vec(item(), 1..10)) {
+            let printed = print(&dm, &items);
+
+            prop_assert_eq!(Day19::parse(&printed).unwrap(), (dm, items));
+        }
+    }
+}
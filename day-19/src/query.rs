@@ -0,0 +1,37 @@
+use aoc_common::query::{Command, Query};
+use aoc_common::{Error, Result};
+
+use crate::{Day19, DecisionMaker, Item};
+
+const EVAL: Command = Command { name: "eval", usage: "{x=..,m=..,a=..,s=..}", help: "the workflows a part goes through, and whether it is accepted" };
+const WORKFLOW: Command = Command { name: "workflow", usage: "<name>", help: "the rules of a workflow" };
+
+impl Query for Day19 {
+    const COMMANDS: &'static [Command] = &[EVAL, WORKFLOW];
+
+    fn query((dm, _): &(DecisionMaker, Vec<Item>), command: &str, arguments: &[&str]) -> Result<String> {
+        match command {
+            "eval" => {
+                let [item] = EVAL.arguments(arguments)?;
+                let item = Item::from(item)?;
+                let (path, _) = dm.workflow_path(&item);
+
+                let verdict = match path.last() {
+                    Some(&"A") => format!("accepted, rating {}", item.value()),
+                    _ => String::from("rejected")
+                };
+
+                Ok(format!("{}\n{}", path.join(" -> "), verdict))
+            },
+            "workflow" => {
+                let [name] = WORKFLOW.arguments(arguments)?;
+                let Some(workflow) = dm.rules.get(name) else {
+                    return Err(Error::Usage(format!("there is no workflow named {}", name)));
+                };
+
+                Ok(format!("{}{{{}}}", name, workflow))
+            },
+            _ => Err(Error::Usage(format!("there is no command {}", command)))
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use std::fmt;
+use aoc_common::parse::{self, Parser};
+use aoc_common::{input, Answer, Error, Result, Solution};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Cube Conundrum";
+
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines = input::lines(input);
+
+        lines.iter().map(|&line|
+            Game::from(line)
+        ).collect()
+    }
+
+    fn part1(games: &Self::Input) -> Answer {
+        let game_sum: u64 = games.iter().map(|game|
+            if game.is_valid() {
+                game.id as u64
+            } else {
+                0
+            }
+        ).sum();
+
+        game_sum.into()
+    }
+
+    fn part2(games: &Self::Input) -> Answer {
+        let game_sum: u128 = games.iter().map(|game|
+            game.power()
+        ).sum();
+
+        game_sum.into()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Game {
+    id: u32,
+    pulls: Vec<Pull>
+}
+
+impl Game {
+    fn from(game_line: &str) -> Result<Self> {
+        // example line: Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+
+        parse::all(game_line, |p| {
+            p.expect("Game ")?;
+            let game_id = p.integer::<u32>()?;
+            p.expect(":")?;
+            p.spaces();
+            let pulls = p.separated(";", Pull::from)?;
+
+            Ok(Game { id: game_id, pulls })
+        })
+    }
+
+    fn is_valid(&self) -> bool {
+        for pull in self.pulls.iter() {
+            if !pull.is_valid() {
+                return false
+            }
+        }
+
+        true
+    }
+
+    // three counts of up to a u32 each, their product needs more than a u64
+    fn power(&self) -> u128 {
+        let max_red = self.pulls.iter().map(|pull|
+            pull.num_red
+        ).max().expect("Red needs a maximum");
+
+        let max_green = self.pulls.iter().map(|pull|
+            pull.num_green
+        ).max().expect("Green needs a maximum");
+
+        let max_blue = self.pulls.iter().map(|pull|
+            pull.num_blue
+        ).max().expect("Blue needs a maximum");
+
+        max_red as u128 * max_green as u128 * max_blue as u128
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Pull {
+    num_red: u32,
+    num_green: u32,
+    num_blue: u32
+}
+
+impl Pull {
+    fn from(p: &mut Parser) -> Result<Self> {
+        let mut red = 0;
+        let mut green = 0;
+        let mut blue = 0;
+
+        let cubes = p.separated(",", |p| {
+            let cube_count = p.integer::<u32>()?;
+            p.expect(" ")?;
+
+            Ok((cube_count, p.word()?))
+        })?;
+        for (cube_count, colour) in cubes {
+
+            match colour {
+                "red" => red = cube_count,
+                "green" => green = cube_count,
+                "blue" => blue = cube_count,
+                _ => return Err(Error::parse(colour, "unexpected colour"))
+            }
+        }
+
+        Ok(Pull { num_red: red, num_green: green, num_blue: blue })
+    }
+
+    fn is_valid(&self) -> bool {
+        self.num_red <= 12 && self.num_green <= 13 && self.num_blue <= 14
+    }
+}
+
+// prints a game the way it is written in the input
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pulls: Vec<String> = self.pulls.iter().map(|pull| pull.to_string()).collect();
+
+        write!(f, "Game {}: {}", self.id, pulls.join("; "))
+    }
+}
+
+// colours which were not pulled are left out, like in the input
+impl fmt::Display for Pull {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cubes: Vec<String> = [(self.num_red, "red"), (self.num_green, "green"), (self.num_blue, "blue")].iter()
+            .filter(|(count, _)| *count > 0)
+            .map(|(count, colour)| format!("{} {}", count, colour))
+            .collect();
+
+        write!(f, "{}", cubes.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{examples, Answer, Solution};
+    use proptest::prelude::*;
+
+    use super::{Day2, Game, Pull};
+
+    #[test]
+    fn test_examples() {
+        examples::check(&Day2, env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_large_numbers() {
+        let games = Day2::parse("Game 4294967295: 1 red\nGame 4294967295: 1 blue\nGame 1: 4294967295 red, 4294967295 green, 4294967295 blue").unwrap();
+
+        assert_eq!(Day2::part1(&games), Answer::from(2 * 4294967295u64));
+        assert_eq!(Day2::part2(&games), Answer::from(4294967295u128 * 4294967295 * 4294967295));
+    }
+
+    fn pull() -> impl Strategy<Value = Pull> {
+        (0..20u32, 0..20u32, 0..20u32).prop_filter("a pull has cubes", |(r, g, b)| r + g + b > 0).prop_map(|(num_red, num_green, num_blue)|
+            Pull { num_red, num_green, num_blue }
+        )
+    }
+
+    fn game() -> impl Strategy<Value = Game> {
+        (0..1000u32, prop::collection::vec(pull(), 1..6)).prop_map(|(id, pulls)| Game { id, pulls })
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(games in prop::collection::vec(game(), 1..10)) {
+            let printed: Vec<String> = games.iter().map(|game| game.to_string()).collect();
+
+            prop_assert_eq!(Day2::parse(&printed.join("\n")).unwrap(), games);
+        }
+    }
+}
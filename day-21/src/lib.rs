@@ -0,0 +1,140 @@
+mod query;
+mod visualize;
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use aoc_common::{graph, Answer, Error, Grid, Result, Solution};
+use aoc_common::grid::Position;
+
+pub struct Day21;
+
+const STEPS: u64 = 64;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    const TITLE: &'static str = "Step Counter";
+
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Map::from(input)
+    }
+
+    fn part1(m: &Self::Input) -> Answer {
+        m.inspect(STEPS).into()
+    }
+
+    fn part2(_m: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Map {
+    // true for every garden plot, false for rocks
+    plots: Grid<bool>,
+    start: Position
+}
+
+impl Map {
+    fn from(input: &str) -> Result<Self> {
+        let tiles = Grid::parse(input, |item, token| match item {
+            'S' | '.' | '#' => Ok(item),
+            _ => Err(Error::parse(token, "unknown tile"))
+        })?;
+
+        let Some(start) = tiles.find(|&t| t == 'S') else {
+            return Err(Error::parse(&input[input.len()..], "there is no starting position on the map"));
+        };
+        let plots = tiles.map(|&t| t != '#');
+
+        Ok(Map { plots, start })
+    }
+
+    fn inspect(&self, max_steps: u64) -> u64 {
+        self.reachable(&self.distances(), max_steps).len() as u64
+    }
+
+    // the number of steps to every plot which can be reached from the start
+    fn distances(&self) -> HashMap<Position, usize> {
+        graph::bfs(self.start, |node|
+            self.plots.neighbours4(node).filter(|&n| self.plots[n])
+        )
+    }
+
+    // the plots the elf can stand on after exactly that many steps. Every step changes between
+    // odd and even x + y, and a plot can be left and entered again to waste two steps. So these
+    // are the plots at most that far away which have the same parity as the steps
+    fn reachable(&self, distances: &HashMap<Position, usize>, steps: u64) -> HashSet<Position> {
+        if steps > 0 && distances.len() == 1 {
+            return HashSet::new(); // the start is walled in, there is no step to take
+        }
+
+        distances.iter()
+            .filter(|&(_, &d)| d as u64 <= steps && d as u64 % 2 == steps % 2)
+            .map(|(&position, _)| position)
+            .collect()
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut tiles = self.plots.map(|&plot| if plot { '.' } else { '#' });
+        tiles[self.start] = 'S';
+
+        write!(f, "{}", tiles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use aoc_common::grid::Position;
+    use aoc_common::{examples, Grid, Solution};
+    use proptest::prelude::*;
+
+    use super::{Day21, Map};
+
+    // the plots after every step, one step at a time
+    fn walk(m: &Map, steps: u64) -> u64 {
+        let mut current_nodes: HashSet<Position> = HashSet::from([m.start]);
+        for _ in 0..steps {
+            current_nodes = current_nodes.iter()
+                .flat_map(|&node| m.plots.neighbours4(node))
+                .filter(|&n| m.plots[n])
+                .collect();
+        }
+
+        current_nodes.len() as u64
+    }
+
+    #[test]
+    fn test_examples() {
+        examples::check(&Day21, env!("CARGO_MANIFEST_DIR"));
+    }
+
+    fn map() -> impl Strategy<Value = Map> {
+        (1..12usize, 1..12usize).prop_flat_map(|(width, height)|
+            (prop::collection::vec(any::<bool>(), width * height), 0..width, 0..height)
+                .prop_map(move |(mut cells, x, y)| {
+                    // the start is always a garden plot
+                    cells[y * width + x] = true;
+
+                    Map { plots: Grid::new(width, height, cells), start: (x, y) }
+                })
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(map in map()) {
+            prop_assert_eq!(Day21::parse(&map.to_string()).unwrap(), map);
+        }
+
+        #[test]
+        fn test_inspect(map in map(), steps in 0..30u64) {
+            prop_assert_eq!(map.inspect(steps), walk(&map, steps));
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use aoc_common::query::{Command, Query};
+use aoc_common::{input, Error, Result};
+
+use crate::{Day21, Map};
+
+const STEPS: Command = Command { name: "steps", usage: "<n>", help: "the garden plots which can be reached in exactly n steps" };
+
+impl Query for Day21 {
+    const COMMANDS: &'static [Command] = &[STEPS];
+
+    fn query(m: &Map, command: &str, arguments: &[&str]) -> Result<String> {
+        match command {
+            "steps" => {
+                let [steps] = STEPS.arguments(arguments)?;
+                let steps = input::number::<u64>(steps)?;
+
+                Ok(format!("{} plots can be reached in {} steps", m.inspect(steps), steps))
+            },
+            _ => Err(Error::Usage(format!("there is no command {}", command)))
+        }
+    }
+}
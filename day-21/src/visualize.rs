@@ -0,0 +1,36 @@
+use std::collections::HashSet;
+
+use aoc_common::grid::Position;
+use aoc_common::visualize::{Cell, Colour, Frame, Visualize};
+use aoc_common::Part;
+
+use crate::{Day21, Map, STEPS};
+
+fn draw(m: &Map, reachable: &HashSet<Position>, steps: u64) -> Frame {
+    let mut cells = m.plots.map(|&plot| if plot {
+        Cell::coloured('·', Colour::Grey)
+    } else {
+        Cell::coloured('█', Colour::Blue)
+    });
+    for &position in reachable.iter() {
+        cells[position] = Cell::coloured('O', Colour::Green);
+    }
+    if !reachable.contains(&m.start) {
+        cells[m.start] = Cell::coloured('S', Colour::Red);
+    }
+
+    Frame::new(format!("{} plots reachable in exactly {} steps", reachable.len(), steps), cells)
+}
+
+impl Visualize for Day21 {
+    // the plots the elf can be on after every step of part 1
+    fn frames(m: &Map, part: Part) -> Vec<Frame> {
+        if part == Part::Two {
+            return Vec::new();
+        }
+
+        let distances = m.distances();
+
+        (0..=STEPS).map(|steps| draw(m, &m.reachable(&distances, steps), steps)).collect()
+    }
+}
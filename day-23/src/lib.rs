@@ -0,0 +1,156 @@
+mod visualize;
+
+use aoc_common::graph::{Graph, Path};
+use aoc_common::{Answer, Direction, Error, Grid, Result, Solution};
+use aoc_common::grid::Position;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    const TITLE: &'static str = "A Long Walk";
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let tiles = Grid::parse(input, |item, token| match item {
+            '#' | '.' | '>' | '<' | 'v' | '^' => Ok(item),
+            _ => Err(Error::parse(token, "unknown tile"))
+        })?;
+
+        // the hike starts in the first row and ends in the last one
+        if start(&tiles).is_none() || end(&tiles).is_none() {
+            return Err(Error::parse(&input[input.len()..], "the map needs an open tile in its first and last row"));
+        }
+
+        Ok(tiles)
+    }
+
+    fn part1(tiles: &Self::Input) -> Answer {
+        let hf = HikingFun::from(tiles, true);
+
+        match hf.and_then(|hf| hf.longest_path()) {
+            Some(path) => path.into(),
+            None => Answer::Unsolved
+        }
+    }
+
+    fn part2(tiles: &Self::Input) -> Answer {
+        let hf = HikingFun::from(tiles, false);
+
+        match hf.and_then(|hf| hf.longest_path()) {
+            Some(path) => path.into(),
+            None => Answer::Unsolved
+        }
+    }
+}
+
+type Node = Position;
+
+// the open tile in the first row
+fn start(tiles: &Grid<char>) -> Option<Node> {
+    (0..tiles.width()).map(|x| (x, 0)).find(|&n| tiles[n] != '#')
+}
+
+// the open tile in the last row
+fn end(tiles: &Grid<char>) -> Option<Node> {
+    let last = tiles.height().checked_sub(1)?;
+
+    (0..tiles.width()).map(|x| (x, last)).find(|&n| tiles[n] != '#')
+}
+
+struct HikingFun {
+    // the junctions of the trails, with the trails between them as edges
+    trails: Graph<Node>,
+    start_node: Node,
+    end_node: Node
+}
+
+impl HikingFun {
+    // with slopes, arrows can only be walked in their direction. None without a start or an end
+    fn from(tiles: &Grid<char>, slopes: bool) -> Option<Self> {
+        let start_node = start(tiles)?;
+        let end_node = end(tiles)?;
+
+        // now search for neighbours
+        let tiles_graph = Graph::from_grid(tiles, |coordinate, &node_symbol| {
+            if node_symbol == '#' {
+                return None;
+            }
+
+            let mut node_neighbours: Vec<Node> = Vec::new();
+            for direction in Direction::ALL {
+                let Some(neighbour) = tiles.step(coordinate, direction) else { continue };
+                if tiles[neighbour] == '#' {
+                    continue;
+                }
+
+                // an arrow can only be left into the direction it points to
+                let valid_edge = node_symbol == '.' || !slopes || node_symbol == direction.arrow();
+
+                if valid_edge {
+                    node_neighbours.push(neighbour);
+                }
+            }
+
+            Some(node_neighbours)
+        });
+        // the hike only has a choice at the junctions
+        let trails = tiles_graph.contract(|n| n == start_node || n == end_node);
+
+        Some(HikingFun { trails, start_node, end_node })
+    }
+
+    fn longest_path(&self) -> Option<i64> {
+        self.longest_hike().map(|hike| hike.length as i64)
+    }
+
+    // all nodes on the longest path from start to end, if there is any
+    fn longest_hike(&self) -> Option<Path<Node>> {
+        self.trails.longest_path(self.start_node, self.end_node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{examples, Answer, Grid, Solution};
+    use proptest::prelude::*;
+
+    use super::Day23;
+
+    #[test]
+    fn test_examples() {
+        examples::check(&Day23, env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_no_hike() {
+        let walled_in = Day23::parse("#.###\n###.#\n#...#").unwrap();
+        assert_eq!(Day23::part1(&walled_in), Answer::Unsolved);
+        assert_eq!(Day23::part2(&walled_in), Answer::Unsolved);
+
+        let e = Day23::parse("#.###\n#...#\n#####").unwrap_err();
+        assert_eq!(e.to_string(), "the map needs an open tile in its first and last row");
+    }
+
+    fn tiles() -> impl Strategy<Value = Grid<char>> {
+        let tile = prop::sample::select(vec!['#', '.', '>', '<', 'v', '^']);
+
+        (1..12usize, 1..12usize).prop_flat_map(move |(width, height)|
+            (prop::collection::vec(tile.clone(), width * height), 0..width, 0..width)
+                .prop_map(move |(mut cells, start, end)| {
+                    cells[start] = '.';
+                    cells[(height - 1) * width + end] = '.';
+
+                    Grid::new(width, height, cells)
+                })
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(tiles in tiles()) {
+            prop_assert_eq!(Day23::parse(&tiles.to_string()).unwrap(), tiles);
+        }
+    }
+}
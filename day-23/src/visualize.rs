@@ -0,0 +1,32 @@
+use aoc_common::visualize::{Cell, Colour, Frame, Visualize};
+use aoc_common::{Grid, Part};
+
+use crate::{Day23, HikingFun};
+
+impl Visualize for Day23 {
+    // the longest hike in red on the trails, slopes in the colour of the part which
+    // has to follow them
+    fn frames(tiles: &Grid<char>, part: Part) -> Vec<Frame> {
+        let slopes = part == Part::One;
+        let hike = HikingFun::from(tiles, slopes).and_then(|hf| hf.longest_hike());
+
+        let mut cells = tiles.map(|&tile| match tile {
+            '#' => Cell::coloured('█', Colour::Green),
+            '.' => Cell::plain(' '),
+            _ if slopes => Cell::coloured(tile, Colour::Cyan),
+            _ => Cell::coloured(tile, Colour::Grey)
+        });
+        let Some(hike) = hike else {
+            return vec![Frame::new(String::from("there is no hike from the start to the end"), cells)];
+        };
+        for &position in hike.nodes.iter() {
+            let symbol = match tiles[position] {
+                '.' => '●',
+                slope => slope
+            };
+            cells[position] = Cell::coloured(symbol, Colour::Red);
+        }
+
+        vec![Frame::new(format!("the longest hike, {} steps", hike.length), cells)]
+    }
+}
@@ -0,0 +1,100 @@
+use aoc_common::generate::Generator;
+use aoc_common::random::Rng;
+use aoc_common::{Answer, Part};
+
+use crate::Day24;
+
+const AREA_MIN: i128 = 200000000000000;
+const AREA_MAX: i128 = 400000000000000;
+
+// the largest speed of the rock along each axis
+const ROCK_SPEED: i128 = 25;
+
+type Vector = [i128; 3];
+
+fn parse(input: &str) -> Vec<(Vector, Vector)> {
+    input.trim().lines().map(|line| {
+        let numbers: Vec<i128> = line.split([',', '@'])
+            .map(|n| n.trim().parse().unwrap())
+            .collect();
+
+        ([numbers[0], numbers[1], numbers[2]], [numbers[3], numbers[4], numbers[5]])
+    }).collect()
+}
+
+// where the x/y paths of two hailstones cross in the future of both, counted with
+// exact fractions instead of floats
+fn crosses_inside(a: &(Vector, Vector), b: &(Vector, Vector)) -> bool {
+    let ((pa, va), (pb, vb)) = (a, b);
+    let mut det = vb[0] * va[1] - va[0] * vb[1];
+    if det == 0 {
+        return false;
+    }
+
+    // a is at the crossing after t / det, b after s / det
+    let mut t = vb[0] * (pb[1] - pa[1]) - vb[1] * (pb[0] - pa[0]);
+    let mut s = va[0] * (pb[1] - pa[1]) - va[1] * (pb[0] - pa[0]);
+    if det < 0 {
+        (det, t, s) = (-det, -t, -s);
+    }
+    if t < 0 || s < 0 {
+        return false;
+    }
+
+    let x = pa[0] * det + va[0] * t;
+    let y = pa[1] * det + va[1] * t;
+
+    (AREA_MIN * det..=AREA_MAX * det).contains(&x) && (AREA_MIN * det..=AREA_MAX * det).contains(&y)
+}
+
+impl Generator for Day24 {
+    // size hailstones which are all hit by one rock, like in the real input
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let position: Vector = [0; 3].map(|_| rng.range(250000000000000..350000000000000) as i128);
+        let velocity: Vector = [0; 3].map(|_| rng.range(-ROCK_SPEED as i64..ROCK_SPEED as i64 + 1) as i128);
+
+        let mut times: Vec<i128> = Vec::new();
+        while times.len() < size.max(3) {
+            let t = rng.range(100000000000..1000000000000) as i128;
+            if !times.contains(&t) {
+                times.push(t);
+            }
+        }
+
+        times.iter().map(|&t| {
+            let v: Vector = [0; 3].map(|_| rng.range(-150..151) as i128);
+            let p: Vector = [0, 1, 2].map(|i| position[i] + (velocity[i] - v[i]) * t);
+
+            format!("{}, {}, {} @ {}, {}, {}\n", p[0], p[1], p[2], v[0], v[1], v[2])
+        }).collect()
+    }
+
+    // part 1 looks at every pair, day 24 does not solve part 2
+    fn reference(input: &str, part: Part) -> Answer {
+        let hail = parse(input);
+
+        match part {
+            Part::One => {
+                let mut count = 0;
+                for (i, a) in hail.iter().enumerate() {
+                    count = count + hail[i + 1..].iter().filter(|b| crosses_inside(a, b)).count();
+                }
+
+                count.into()
+            },
+            Part::Two => Answer::Unsolved
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate;
+
+    use crate::Day24;
+
+    #[test]
+    fn test_generated() {
+        generate::check(&Day24, 20, 30);
+    }
+}
@@ -0,0 +1,201 @@
+mod generate;
+mod picture;
+
+use std::fmt;
+use aoc_common::number::{self, BigInt, Rational};
+use aoc_common::parse::{self, Parser};
+use aoc_common::{input, parallel, Answer, Result, Solution};
+
+pub struct Day24;
+
+// the test area of part 1, on both the x and the y axis
+const AREA: (i64, i64) = (200000000000000, 400000000000000);
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    const TITLE: &'static str = "Never Tell Me The Odds";
+
+    type Input = Vec<Hail>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines = input::lines(input);
+
+        lines.iter().map(|line| Hail::from(line) ).collect()
+    }
+
+    fn part1(the_storm: &Self::Input) -> Answer {
+        crossings(the_storm, AREA).into()
+    }
+
+    fn part2(_the_storm: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+// how many pairs of hailstones cross within the area in the future, the example has an area
+// of its own
+fn crossings(the_storm: &[Hail], area: (i64, i64)) -> u64 {
+    // every hailstone counts its crossings with all the others on its own thread
+    let crossings = parallel::map(the_storm, |hail| {
+        let mut counter = 0;
+        for other in the_storm.iter() {
+            if hail != other {
+                if let Some(intersection) = hail.future_intersect(other) {
+                    if in_area(&intersection, area) {
+                        counter = counter + 1;
+                    }
+                }
+            }
+        }
+
+        counter
+    });
+    let counter: u64 = crossings.iter().sum();
+
+    counter / 2
+}
+
+fn in_area(point: &(Rational<BigInt>, Rational<BigInt>), area: (i64, i64)) -> bool {
+    let area = Rational::from_integer(BigInt::from(area.0))..=Rational::from_integer(BigInt::from(area.1));
+
+    area.contains(&point.0) && area.contains(&point.1)
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Hail  {
+    s_x: i64,
+    s_y: i64,
+    s_z: i64,
+
+    v_x: i64,
+    v_y: i64,
+    v_z: i64
+}
+
+impl Hail {
+    fn from(line: &str) -> Result<Self> {
+        // 19, 13, 30 @ -2,  1, -2
+        parse::all(line, |p| {
+            let (s_x, s_y, s_z) = Hail::coordinates(p)?;
+            p.spaces();
+            p.expect("@")?;
+            p.spaces();
+            let (v_x, v_y, v_z) = Hail::coordinates(p)?;
+
+            Ok(Hail { s_x, s_y, s_z, v_x, v_y, v_z })
+        })
+    }
+
+    // three integers separated by commas
+    fn coordinates(p: &mut Parser) -> Result<(i64, i64, i64)> {
+        let x = p.integer::<i64>()?;
+        p.spaces();
+        p.expect(",")?;
+        p.spaces();
+        let y = p.integer::<i64>()?;
+        p.spaces();
+        p.expect(",")?;
+        p.spaces();
+        let z = p.integer::<i64>()?;
+
+        Ok((x, y, z))
+    }
+
+    // BigInt, the products of the coordinates of the input already come close to an i128
+    fn position(&self) -> [BigInt; 3] {
+        [self.s_x, self.s_y, self.s_z].map(BigInt::from)
+    }
+
+    fn velocity(&self) -> [BigInt; 3] {
+        [self.v_x, self.v_y, self.v_z].map(BigInt::from)
+    }
+
+    fn future_intersect(&self, other: &Hail) -> Option<(Rational<BigInt>, Rational<BigInt>)> {
+        // Example solving
+        // 18 - 19 = -2x - (-1)y
+        // 19 - 13 = 1x - (-1)y
+        // ==>
+        // -1 = -2x + y
+        //  6 = x + y
+
+        // Solutions at: 7/3; 11/3 ==> 2.33; 3.66
+        // Intersection: 19 + (7/3)*(-2) = 14.33
+        //               13 + (7/3)*(1)  = 15.33
+
+
+        // Hailstone A: 19, 13, 30 @ -2, 1, -2
+        // Hailstone B: 18, 19, 22 @ -1, -1, -2
+        // Hailstones' paths will cross inside the test area (at x=14.333, y=15.333).
+
+        let (p, v) = (self.position(), self.velocity());
+        let (q, w) = (other.position(), other.velocity());
+        let rows = vec![
+            vec![v[0].clone(), -&w[0], &q[0] - &p[0]],
+            vec![v[1].clone(), -&w[1], &q[1] - &p[1]]
+        ];
+        let result = number::solve(rows.into_iter().map(|row| row.into_iter().map(Rational::from_integer).collect()).collect())?;
+
+        // only report an intersection if it happens in the future!
+        let zero = Rational::from_integer(BigInt::from(0));
+        if result[0] >= zero && result[1] >= zero {
+            let t = &result[0];
+            let intersection = (t * &v[0] + &p[0], t * &v[1] + &p[1]);
+            return Some(intersection);
+        }
+
+        None
+    }
+}
+
+impl fmt::Display for Hail {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, {}, {} @ {}, {}, {}", self.s_x, self.s_y, self.s_z, self.v_x, self.v_y, self.v_z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::number::{BigInt, Rational};
+    use aoc_common::{examples, Solution};
+    use proptest::prelude::*;
+
+    use super::{crossings, Day24, Hail};
+
+    #[test]
+    fn test_examples() {
+        examples::check(&Day24, env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_crossings() {
+        let the_storm = Day24::parse(include_str!("../fixtures/example.txt")).unwrap();
+
+        // the paths of A and B cross at x=14.333, y=15.333
+        let intersection = the_storm[0].future_intersect(&the_storm[1]).unwrap();
+        let third = |n: i64| Rational::new(BigInt::from(n), BigInt::from(3));
+        assert_eq!(intersection, (third(43), third(46)));
+        assert_eq!(crossings(&the_storm, (7, 27)), 2);
+
+        // the products of coordinates this large do not fit into an i128
+        let the_storm = Day24::parse("9223372036854775807, 0, 0 @ -9223372036854775808, 1, 0\n0, -9223372036854775808, 0 @ 1, 9223372036854775807, 0").unwrap();
+        assert!(the_storm[0].future_intersect(&the_storm[1]).is_some());
+    }
+
+    fn hail() -> impl Strategy<Value = Hail> {
+        // whole numbers only, that is all the input has
+        let position = -500000000000000i64..500000000000000i64;
+        let velocity = -1000i64..1000i64;
+
+        (position.clone(), position.clone(), position, velocity.clone(), velocity.clone(), velocity)
+            .prop_map(|(s_x, s_y, s_z, v_x, v_y, v_z)| Hail { s_x, s_y, s_z, v_x, v_y, v_z })
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(storm in prop::collection::vec(hail(), 1..10)) {
+            let printed: Vec<String> = storm.iter().map(|hail| hail.to_string()).collect();
+
+            prop_assert_eq!(Day24::parse(&printed.join("\n")).unwrap(), storm);
+        }
+    }
+}
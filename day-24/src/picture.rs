@@ -0,0 +1,75 @@
+use aoc_common::image::{Draw, Image, Rgb, Svg};
+use aoc_common::number::{BigInt, Rational, ToPrimitive};
+use aoc_common::Part;
+
+use crate::{in_area, Day24, Hail, AREA};
+
+// the test area is drawn 1000 units wide, its real size is too much for SVG viewers
+const SIZE: f64 = 1000.;
+
+const TRAIL: Rgb = Rgb(70, 130, 180);
+const CROSSING: Rgb = Rgb(220, 30, 30);
+
+fn scale(point: (f64, f64)) -> (f64, f64) {
+    let (min, max) = (AREA.0 as f64, AREA.1 as f64);
+    let factor = SIZE / (max - min);
+
+    ((point.0 - min) * factor, (point.1 - min) * factor)
+}
+
+// near enough for a picture
+fn approximate(r: &Rational<BigInt>) -> f64 {
+    r.to_f64().unwrap_or(f64::NAN)
+}
+
+// the times between which the hailstone is within the test area in x and y, if it ever is
+fn time_in_area(hail: &Hail) -> Option<(f64, f64)> {
+    let mut from: f64 = 0.;
+    let mut to = f64::INFINITY;
+    for (position, velocity) in [(hail.s_x, hail.v_x), (hail.s_y, hail.v_y)] {
+        if velocity == 0 {
+            if position < AREA.0 || position > AREA.1 {
+                return None;
+            }
+            continue;
+        }
+
+        let enter = (AREA.0 - position) as f64 / velocity as f64;
+        let leave = (AREA.1 - position) as f64 / velocity as f64;
+        from = from.max(enter.min(leave));
+        to = to.min(enter.max(leave));
+    }
+
+    if from <= to && to.is_finite() { Some((from, to)) } else { None }
+}
+
+impl Draw for Day24 {
+    // the paths of the hailstones through the test area of part 1, with a dot wherever
+    // two of them cross in the future
+    fn draw(the_storm: &Vec<Hail>, part: Part) -> Option<Image> {
+        if part == Part::Two {
+            return None;
+        }
+
+        let mut svg = Svg::new(0., 0., SIZE, SIZE);
+        svg.rect((0., 0.), (SIZE, SIZE), Some(Rgb::WHITE), Rgb::BLACK);
+        for hail in the_storm.iter() {
+            let Some((from, to)) = time_in_area(hail) else { continue };
+            let at = |t: f64| scale((hail.s_x as f64 + t * hail.v_x as f64, hail.s_y as f64 + t * hail.v_y as f64));
+
+            svg.line(at(from), at(to), TRAIL);
+        }
+
+        for (i, hail) in the_storm.iter().enumerate() {
+            for other in the_storm[i + 1..].iter() {
+                if let Some(intersection) = hail.future_intersect(other) {
+                    if in_area(&intersection, AREA) {
+                        svg.circle(scale((approximate(&intersection.0), approximate(&intersection.1))), 2., CROSSING);
+                    }
+                }
+            }
+        }
+
+        Some(Image::Svg(svg))
+    }
+}
@@ -0,0 +1,231 @@
+use std::fmt;
+use aoc_common::parse::Parser;
+use aoc_common::{input, Answer, Result, Solution};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Gear Ratios";
+
+    type Input = Plan;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines = input::lines(input);
+
+        Plan::from(lines)
+    }
+
+    fn part1(plan: &Self::Input) -> Answer {
+        let part_numbers = plan.find_part_numbers();
+        let sum: u64 = part_numbers.iter().map(|&n| n as u64).sum();
+
+        sum.into()
+    }
+
+    fn part2(plan: &Self::Input) -> Answer {
+        let gears_values = plan.find_gear_values();
+        // two numbers of up to ten digits, their products add up to more than a u64
+        let gear_ration_sum: u128 = gears_values.iter().map(|&(part1, part2)|
+            part1 as u128 * part2 as u128
+        ).sum();
+
+        gear_ration_sum.into()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Plan {
+    symbols: Vec<Symbol>, // all symbols in the plan
+    numbers: Vec<Number> // all numbers in the plan
+}
+
+impl Plan {
+    fn from(map: Vec<&str>) -> Result<Self> {
+        let mut symbols: Vec<Symbol> = Vec::new();
+        let mut numbers: Vec<Number> = Vec::new();
+
+        for (index, &line) in map.iter().enumerate() {
+            Plan::parse_line(index as u32, line, &mut symbols, &mut numbers)?;
+        }
+
+        Ok(Plan { symbols, numbers })
+    }
+
+    fn parse_line(line_no: u32, content: &str, symbols: &mut Vec<Symbol>, numbers: &mut Vec<Number>) -> Result<()> {
+        let mut p = Parser::new(content);
+        while let Some(c) = p.peek() {
+            let start = p.offset() as u32;
+            if c.is_ascii_digit() {
+                let number = input::number::<u32>(p.take_while(|c| c.is_ascii_digit()))?;
+                let end = p.offset() as u32 - 1;
+
+                numbers.push(
+                    Number { value: number, line: line_no, position: (start, end) }
+                )
+            } else {
+                let (symbol, _) = p.char()?;
+                if symbol != '.' {
+                    symbols.push(
+                        Symbol { value: symbol, position: (start, line_no) }
+                    )
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn find_part_numbers(&self) -> Vec<u32> {
+        let mut part_numbers: Vec<u32> = Vec::new();
+
+        for number in self.numbers.iter() {
+            if self.is_part_number(number) {
+                part_numbers.push(number.value);
+            }
+        }
+
+        part_numbers
+    }
+
+    fn is_part_number(&self, number: &Number) -> bool {
+        for symbol in self.symbols.iter() {
+            if number.intersect_symbol(symbol) {
+                return true
+            }
+        }
+
+        false
+    }
+
+    fn find_gear_values(&self) -> Vec<(u32, u32)> {
+        let mut gear_values: Vec<(u32, u32)> = Vec::new();
+
+        for symbol in self.symbols.iter() {
+            if !symbol.is_gear_value() {
+                continue;
+            }
+
+            let adjacent_numbers = self.find_adjacent_numbers(symbol);
+            if adjacent_numbers.len() != 2 {
+                continue;
+            }
+            let n1 = adjacent_numbers.get(0).unwrap().value;
+            let n2 = adjacent_numbers.get(1).unwrap().value;
+
+            gear_values.push((n1, n2));
+        }
+
+        gear_values
+    }
+
+    fn find_adjacent_numbers(&self, symbol: &Symbol) -> Vec<&Number> {
+        let mut adjacent_numbers: Vec<&Number> = Vec::new();
+
+        for number in self.numbers.iter() {
+            if number.intersect_symbol(symbol) {
+                adjacent_numbers.push(number);
+            }
+        }
+
+        adjacent_numbers
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Number {
+    value: u32,
+    line: u32,
+    position: (u32, u32)
+}
+
+impl Number {
+    fn intersect_symbol(&self, symbol: &Symbol) -> bool {
+        // check if y can match
+        let min_y = if self.line > 0 { self.line - 1 } else { 0 };
+        if !(min_y <= symbol.position.1 && symbol.position.1 <= self.line + 1) {
+            return false
+        }
+
+        // check if x can match
+        let min_x = if self.position.0 > 0 { self.position.0 - 1 } else { 0 };
+        if !(min_x <= symbol.position.0 && symbol.position.0 <= self.position.1 + 1) {
+            return false
+        }
+
+        true
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Symbol {
+    value: char,
+    position: (u32, u32)
+}
+
+impl Symbol {
+    fn is_gear_value(&self) -> bool {
+        self.value == '*'
+    }
+}
+
+// draws the plan again, with dots wherever there is neither a number nor a symbol.
+// Numbers keep their width, so leading zeros come back as well
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let height = self.numbers.iter().map(|n| n.line)
+            .chain(self.symbols.iter().map(|s| s.position.1))
+            .max().map(|h| h + 1).unwrap_or(0);
+        let width = self.numbers.iter().map(|n| n.position.1)
+            .chain(self.symbols.iter().map(|s| s.position.0))
+            .max().map(|w| w + 1).unwrap_or(0);
+
+        let mut map = vec![vec!['.'; width as usize]; height as usize];
+        for number in self.numbers.iter() {
+            let width = (number.position.1 - number.position.0 + 1) as usize;
+            let digits = format!("{:0width$}", number.value, width = width);
+            for (i, digit) in digits.chars().enumerate() {
+                map[number.line as usize][number.position.0 as usize + i] = digit;
+            }
+        }
+        for symbol in self.symbols.iter() {
+            map[symbol.position.1 as usize][symbol.position.0 as usize] = symbol.value;
+        }
+
+        let lines: Vec<String> = map.iter().map(|line| line.iter().collect()).collect();
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{examples, Answer, Solution};
+    use proptest::prelude::*;
+
+    use super::Day3;
+
+    #[test]
+    fn test_examples() {
+        examples::check(&Day3, env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_large_numbers() {
+        let plan = Day3::parse("4294967295*4294967295\n.....................\n4294967295*4294967295").unwrap();
+
+        assert_eq!(Day3::part1(&plan), Answer::from(4 * 4294967295u64));
+        assert_eq!(Day3::part2(&plan), Answer::from(2 * 4294967295u128 * 4294967295));
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(plan in "[.0-9*#+$/]{1,30}(\n[.0-9*#+$/]{1,30}){0,8}") {
+            // runs of digits which are too long for a number do not parse in the first place
+            let parsed = Day3::parse(&plan);
+            prop_assume!(parsed.is_ok());
+            let plan = parsed.unwrap();
+
+            prop_assert_eq!(Day3::parse(&plan.to_string()).unwrap(), plan);
+        }
+    }
+}
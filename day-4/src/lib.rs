@@ -0,0 +1,183 @@
+use std::collections::HashSet;
+use std::fmt;
+use aoc_common::parse::{self, Parser};
+use aoc_common::{input, Answer, Error, Result, Solution};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Scratchcards";
+
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines = input::lines(input);
+
+        let games: Vec<Game> = lines.iter().map(|&line| Game::from(line)).collect::<Result<_>>()?;
+
+        // part 2 finds the cards by their id
+        for (i, (game, line)) in games.iter().zip(lines.iter()).enumerate() {
+            if game.id as usize != i + 1 {
+                return Err(Error::parse(line, format!("expected card {}", i + 1)));
+            }
+        }
+
+        Ok(games)
+    }
+
+    fn part1(games: &Self::Input) -> Answer {
+        let intersection_size: Vec<u32> = games.iter().map(|g|
+            g.intersection_size()
+        ).collect();
+
+        // a card is worth 2^(n - 1) for n matches, more than 128 matches do not fit into a u128
+        let game_result: Option<u128> = intersection_size.iter().try_fold(0u128, |sum, elem|
+            if *elem == 0u32 {
+                Some(sum)
+            } else {
+                sum.checked_add(1u128.checked_shl(elem - 1)?)
+            }
+        );
+
+        match game_result {
+            Some(game_result) => game_result.into(),
+            None => Answer::Unsolved
+        }
+    }
+
+    fn part2(games: &Self::Input) -> Answer {
+        let mut scratch_cards: Vec<u32> = games.iter().map(|g| g.id).collect();
+
+        let mut card_counter: u32 = 0;
+        loop {
+            if scratch_cards.len() == 0 {
+                break;
+            }
+
+            card_counter = card_counter + 1;
+
+            let current_id = scratch_cards.remove(0);
+
+            // the ids are 1..=n, see parse
+            let current_game = &games[current_id as usize - 1];
+            let intersection = current_game.intersection_size();
+
+            // there are no cards to win past the end of the table
+            let last_id = (current_id + intersection).min(games.len() as u32);
+            for winning_id in (current_id + 1 ..= last_id).rev() {
+                scratch_cards.insert(0, winning_id);
+            }
+        }
+
+        card_counter.into()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Game {
+    id: u32,
+    winning_numbers: HashSet<u32>,
+    drawn_numbers: HashSet<u32>
+}
+
+impl Game {
+    fn from(line: &str) -> Result<Self> {
+        // Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+        parse::all(line, |p| {
+            p.expect("Card")?;
+            p.spaces();
+            let id = p.integer::<u32>()?;
+            p.expect(":")?;
+
+            let winning_numbers = Game::extract_numbers(p)?;
+            p.spaces();
+            p.expect("|")?;
+            let drawn_numbers = Game::extract_numbers(p)?;
+
+            Ok(Game { id, winning_numbers, drawn_numbers })
+        })
+    }
+
+    fn extract_numbers(p: &mut Parser) -> Result<HashSet<u32>> {
+        let numbers = p.numbers::<u32>()?;
+
+        Ok(HashSet::from_iter(numbers))
+    }
+
+    fn intersection_size(&self) -> u32 {
+        let intersection: Vec<&u32> = self.winning_numbers.intersection(&self.drawn_numbers).collect();
+
+        intersection.len() as u32
+    }
+}
+
+// the numbers of a card are printed in ascending order, the input has them in any order
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let numbers = |set: &HashSet<u32>| {
+            let mut numbers: Vec<u32> = set.iter().copied().collect();
+            numbers.sort();
+
+            numbers.iter().map(|n| format!("{:2}", n)).collect::<Vec<String>>().join(" ")
+        };
+
+        write!(f, "Card {:3}: {} | {}", self.id, numbers(&self.winning_numbers), numbers(&self.drawn_numbers))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{examples, Answer, Solution};
+    use proptest::prelude::*;
+
+    use super::{Day4, Game};
+
+    #[test]
+    fn test_examples() {
+        examples::check(&Day4, env!("CARGO_MANIFEST_DIR"));
+    }
+
+    // the cards of a table, numbered from 1 on
+    fn games() -> impl Strategy<Value = Vec<Game>> {
+        let numbers = || prop::collection::hash_set(0..100u32, 1..10);
+
+        prop::collection::vec((numbers(), numbers()), 1..10).prop_map(|cards|
+            cards.into_iter().enumerate().map(|(i, (winning_numbers, drawn_numbers))|
+                Game { id: i as u32 + 1, winning_numbers, drawn_numbers }
+            ).collect()
+        )
+    }
+
+    #[test]
+    fn test_card_ids() {
+        let input = "Card 1: 1 | 1\nCard 3: 1 | 1";
+        let e = Day4::parse(input).unwrap_err();
+        assert_eq!(e.locate(input).to_string(), "2:1: expected card 2 at 'Card 3: 1 | 1'");
+
+        // the last card cannot win any cards after it
+        let games = Day4::parse("Card 1: 1 | 2\nCard 2: 1 2 | 1 2").unwrap();
+        assert_eq!(Day4::part2(&games), Answer::Number(2));
+    }
+
+    #[test]
+    fn test_many_matches() {
+        let numbers = |n: u32| (1..=n).map(|i| i.to_string()).collect::<Vec<String>>().join(" ");
+
+        let games = Day4::parse(&format!("Card 1: {0} | {0}", numbers(128))).unwrap();
+        assert_eq!(Day4::part1(&games), Answer::from(1u128 << 127));
+
+        // 2^128 points are more than a u128 holds
+        let games = Day4::parse(&format!("Card 1: {0} | {0}", numbers(129))).unwrap();
+        assert_eq!(Day4::part1(&games), Answer::Unsolved);
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(games in games()) {
+            let printed: Vec<String> = games.iter().map(|game| game.to_string()).collect();
+
+            prop_assert_eq!(Day4::parse(&printed.join("\n")).unwrap(), games);
+        }
+    }
+}
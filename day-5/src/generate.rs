@@ -0,0 +1,86 @@
+use aoc_common::generate::Generator;
+use aoc_common::random::Rng;
+use aoc_common::{Answer, Part};
+
+use crate::Day5;
+
+const CATEGORIES: [&str; 8] = ["seed", "soil", "fertilizer", "water", "light", "temperature", "humidity", "location"];
+
+impl Generator for Day5 {
+    // size seed ranges, all numbers are below 100 * size
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let limit = 100 * size.max(1) as i64;
+
+        let seeds: Vec<String> = (0..size.max(1)).flat_map(|_| {
+            let start = rng.range(0..limit);
+            let length = rng.range(1..20);
+
+            [start.to_string(), length.to_string()]
+        }).collect();
+        let mut text = format!("seeds: {}\n", seeds.join(" "));
+
+        for categories in CATEGORIES.windows(2) {
+            text.push_str(&format!("\n{}-to-{} map:\n", categories[0], categories[1]));
+
+            // the source ranges of a map never overlap, so we walk along the numbers and
+            // map some of the ranges on the way
+            let mut rules: Vec<String> = Vec::new();
+            let mut start = rng.range(0..limit / 4 + 1);
+            while start < limit {
+                let length = rng.range(1..limit / 4 + 2);
+                if rng.chance(0.7) {
+                    rules.push(format!("{} {} {}", rng.range(0..limit), start, length));
+                }
+                start = start + length + rng.range(0..limit / 10 + 1);
+            }
+            if rules.is_empty() {
+                rules.push(format!("{} {} {}", rng.range(0..limit), rng.range(0..limit), 1));
+            }
+
+            rng.shuffle(&mut rules);
+            text.push_str(&rules.join("\n"));
+            text.push('\n');
+        }
+
+        text
+    }
+
+    // every seed is moved through every map, one after the other
+    fn reference(input: &str, part: Part) -> Answer {
+        let numbers = |line: &str| -> Vec<i64> {
+            line.split_whitespace().filter_map(|n| n.parse().ok()).collect()
+        };
+
+        let blocks: Vec<&str> = input.trim().split("\n\n").collect();
+        let seeds = numbers(blocks[0]);
+        let maps: Vec<Vec<Vec<i64>>> = blocks[1..].iter()
+            .map(|block| block.lines().skip(1).map(numbers).collect())
+            .collect();
+
+        let location = |seed: i64| maps.iter().fold(seed, |n, rules| {
+            match rules.iter().find(|r| n >= r[1] && n < r[1] + r[2]) {
+                Some(r) => r[0] + n - r[1],
+                None => n
+            }
+        });
+
+        let seeds: Vec<i64> = match part {
+            Part::One => seeds,
+            Part::Two => seeds.chunks(2).flat_map(|pair| pair[0]..pair[0] + pair[1]).collect()
+        };
+
+        seeds.into_iter().map(location).min().unwrap().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate;
+
+    use crate::Day5;
+
+    #[test]
+    fn test_generated() {
+        generate::check(&Day5, 50, 10);
+    }
+}
@@ -0,0 +1,456 @@
+mod generate;
+mod query;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Range;
+use aoc_common::parse::{self, Parser};
+use aoc_common::trace::{self, Level};
+use aoc_common::interval::IntervalSet;
+use aoc_common::{input, Answer, Error, Result, Solution};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "If You Give A Seed A Fertilizer";
+
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines = input::lines(input);
+
+        Almanac::from(&lines)
+    }
+
+    fn part1(almanac: &Self::Input) -> Answer {
+        let dests: Vec<u64> = almanac.seeds.iter().map(|seed| {
+            let dest = almanac.convert(*seed);
+            trace::explain(Level::Steps, || format!("seed {} ends up at location {}", seed, dest));
+            if trace::enabled(Level::Details) {
+                almanac.explain_conversion(*seed);
+            }
+
+            dest
+        }).collect();
+
+        match dests.into_iter().min() {
+            Some(location) => location.into(),
+            None => Answer::Unsolved
+        }
+    }
+
+    fn part2(almanac: &Self::Input) -> Answer {
+        let Some(ranges) = almanac.seed_ranges() else {
+            return Answer::Unsolved;
+        };
+
+        // the seeds are converted range by range instead of one by one
+        let mut lowest: Vec<i64> = Vec::new();
+        for range in ranges {
+            let (start, end) = (range.start, range.end);
+            let locations = almanac.convert_set(IntervalSet::from_range(range));
+
+            trace::explain(Level::Steps, || match locations.min() {
+                Some(location) => format!("seeds {}..{}: lowest location {}", start, end, location),
+                None => format!("seeds {}..{}: no seeds", start, end)
+            });
+            lowest.extend(locations.min());
+        }
+
+        match lowest.into_iter().min() {
+            Some(location) => location.into(),
+            None => Answer::Unsolved
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: HashMap<Category, ConversionMap>
+}
+
+impl Almanac {
+    fn from(lines: &[&str]) -> Result<Self> {
+        let Some(&seeds_line) = lines.get(0) else {
+            return Err(Error::parse("", "expected a line with the seeds"));
+        };
+        // part 2 reads them as pairs, see seed_ranges
+        let seeds = parse::all(seeds_line, |p| p.labelled("seeds", Parser::numbers::<u64>))?;
+
+        let mut conversion_maps: HashMap<Category, ConversionMap> = HashMap::new();
+        // the header of every map, for the errors below
+        let mut headers: HashMap<Category, &str> = HashMap::new();
+
+        // more than one empty line between the maps leaves empty paragraphs behind
+        for map in input::paragraphs(&lines[1..]).into_iter().filter(|map| !map.is_empty()) {
+            let conversion_map = ConversionMap::from(map)?;
+            let source_dest = conversion_map.source_category.clone();
+            if headers.insert(source_dest, map[0]).is_some() {
+                return Err(Error::parse(map[0], format!("there is another map from {}", source_dest)));
+            }
+
+            conversion_maps.insert(source_dest, conversion_map);
+        }
+
+        // the maps have to lead from seed to location, every category once at most
+        let mut category = Category::Seed;
+        let mut header = seeds_line;
+        let mut seen: Vec<Category> = Vec::new();
+        while category != Category::Location {
+            if seen.contains(&category) {
+                return Err(Error::parse(header, "the maps go round in circles"));
+            }
+            let Some(map) = conversion_maps.get(&category) else {
+                return Err(Error::parse(header, format!("there is no map from {}", category)));
+            };
+
+            seen.push(category);
+            header = headers[&category];
+            category = map.dest_category;
+        }
+
+        Ok(Almanac { seeds, maps: conversion_maps })
+    }
+
+    // part 2 reads the seeds as pairs of a start and a length, and converts them as intervals
+    // of i64. None if they are not pairs or do not fit
+    fn seed_ranges(&self) -> Option<Vec<Range<i64>>> {
+        if !self.seeds.len().is_multiple_of(2) {
+            return None;
+        }
+
+        self.seeds.chunks_exact(2).map(|pair| {
+            let end = pair[0].checked_add(pair[1]).filter(|&end| end <= i64::MAX as u64)?;
+
+            Some(pair[0] as i64..end as i64)
+        }).collect()
+    }
+
+    // Almanac::from makes sure that the maps lead from seed to location, so every map
+    // on the way is there
+    fn convert(&self, soil: u64) -> u64 {
+        let mut map: &ConversionMap = &self.maps[&Category::Seed];
+        let mut dest: u64 = soil;
+
+        loop {
+            dest = map.convert(dest);
+
+            if map.dest_category == Category::Location {
+                break;
+            }
+
+            map = &self.maps[&map.dest_category];
+        }
+
+        dest
+    }
+
+    // like convert, for all values of the set at once
+    fn convert_set(&self, seeds: IntervalSet) -> IntervalSet {
+        let mut map: &ConversionMap = &self.maps[&Category::Seed];
+        let mut dest = seeds;
+
+        loop {
+            dest = map.convert_set(&dest);
+
+            if map.dest_category == Category::Location {
+                break;
+            }
+
+            map = &self.maps[&map.dest_category];
+        }
+
+        dest
+    }
+
+    // every map the seed goes through, with the value before and after it
+    fn conversions(&self, seed: u64) -> Vec<(&ConversionMap, u64, u64)> {
+        let mut map: &ConversionMap = &self.maps[&Category::Seed];
+        let mut value = seed;
+        let mut conversions = Vec::new();
+
+        loop {
+            let dest = map.convert(value);
+            conversions.push((map, value, dest));
+            value = dest;
+
+            if map.dest_category == Category::Location {
+                break;
+            }
+
+            map = &self.maps[&map.dest_category];
+        }
+
+        conversions
+    }
+
+    // the way of a seed through all maps, with the rule which moved it in every category
+    fn explain_conversion(&self, seed: u64) {
+        for (map, value, dest) in self.conversions(seed) {
+            trace::explain(Level::Details, || match map.rule(value) {
+                Some(rule) => format!("  {} {} -> {} {} by rule {} {} {}", map.source_category, value, map.dest_category, dest, rule.dest_start, rule.source_start, rule.range),
+                None => format!("  {} {} -> {} {}, no rule matches", map.source_category, value, map.dest_category, dest)
+            });
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct ConversionMap {
+    source_category: Category,
+    dest_category: Category,
+    conversion_rules: Vec<Rule>
+}
+
+impl ConversionMap {
+    fn from(conversion_matrix: &[&str]) -> Result<Self> {
+        // seed-to-soil map:
+        let (header, conversion_lines) = parse::section(conversion_matrix)?;
+        let (source, dest) = parse::all(header, |p| {
+            let source = p.word()?;
+            p.expect("-to-")?;
+            let dest = p.word()?;
+            p.expect(" map")?;
+
+            Ok((source, dest))
+        })?;
+        if conversion_lines.is_empty() {
+            return Err(Error::parse(header, "the map has no rules"));
+        }
+
+        let source_category = Category::from(source)?;
+        let dest_category = Category::from(dest)?;
+        let mut rules: Vec<Rule> = Vec::new();
+        for conversion_line in conversion_lines.iter() {
+            rules.push(Rule::from(conversion_line)?);
+        }
+
+        Ok(ConversionMap { source_category, dest_category, conversion_rules: rules })
+    }
+
+    fn convert(&self, source: u64) -> u64 {
+        for rule in self.conversion_rules.iter() {
+            if let Some(d) = rule.convert(source) {
+                return d;
+            }
+        }
+
+        source
+    }
+
+    // every rule moves the values it covers, and only values which no earlier rule has
+    // moved already. The rest stays where it is
+    fn convert_set(&self, source: &IntervalSet) -> IntervalSet {
+        let mut left = source.clone();
+        let mut dest = IntervalSet::new();
+        for rule in self.conversion_rules.iter() {
+            let covered = left.intersection(&IntervalSet::from_range(rule.source_range()));
+            dest = dest.union(&covered.shift(rule.dest_start as i64 - rule.source_start as i64));
+            left = left.difference(&covered);
+        }
+
+        dest.union(&left)
+    }
+
+    // the first rule which covers the source, the one convert uses
+    fn rule(&self, source: u64) -> Option<&Rule> {
+        self.conversion_rules.iter().find(|rule| rule.convert(source).is_some())
+    }
+}
+
+#[derive(Eq, Hash, PartialEq, Copy, Clone, Debug)]
+enum Category {
+    Seed,
+    Soil,
+    Fertilizer,
+    Water,
+    Light,
+    Temperature,
+    Humidity,
+    Location
+}
+
+impl Category {
+    // in the order the maps convert them
+    const ALL: [Category; 8] = [
+        Category::Seed, Category::Soil, Category::Fertilizer, Category::Water,
+        Category::Light, Category::Temperature, Category::Humidity, Category::Location
+    ];
+
+    fn from(cat: &str) -> Result<Self> {
+        match cat {
+            "seed" => Ok(Category::Seed),
+            "soil" => Ok(Category::Soil),
+            "fertilizer" => Ok(Category::Fertilizer),
+            "water" => Ok(Category::Water),
+            "light" => Ok(Category::Light),
+            "temperature" => Ok(Category::Temperature),
+            "humidity" => Ok(Category::Humidity),
+            "location" => Ok(Category::Location),
+            _ => Err(Error::parse(cat, "unknown category"))
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Rule {
+    dest_start: u64,
+    source_start: u64,
+    range: u64
+}
+
+impl Rule {
+    fn from(line: &str) -> Result<Self> {
+        let parts = parse::all(line, Parser::numbers::<u64>)?;
+        let [ds, ss, r] = parts[..] else {
+            return Err(Error::parse(line, "expected destination start, source start and length"));
+        };
+        // convert_set moves the ranges as intervals of i64
+        let fits = |start: u64| start.checked_add(r).is_some_and(|end| end <= i64::MAX as u64);
+        if !fits(ds) || !fits(ss) {
+            return Err(Error::parse(line, "the range is too large"));
+        }
+
+        Ok(Rule { dest_start: ds, source_start: ss, range: r })
+    }
+
+    fn source_range(&self) -> Range<i64> {
+        self.source_start as i64..(self.source_start + self.range) as i64
+    }
+
+    fn convert(&self, source: u64) -> Option<u64> {
+        return if source >= self.source_start && source < self.source_start + self.range {
+            let dest = self.dest_start + (source - self.source_start);
+
+            Some(dest)
+        } else {
+            None
+        }
+    }
+}
+
+// the maps come out along the chain from seed to location, like in the puzzle text
+impl fmt::Display for Almanac {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let seeds: Vec<String> = self.seeds.iter().map(|seed| seed.to_string()).collect();
+        write!(f, "seeds: {}", seeds.join(" "))?;
+
+        let mut maps: Vec<&ConversionMap> = self.maps.values().collect();
+        maps.sort_by_key(|map| Category::ALL.iter().position(|&c| c == map.source_category));
+        for map in maps {
+            write!(f, "\n\n{}", map)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for ConversionMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-to-{} map:", self.source_category, self.dest_category)?;
+        for rule in self.conversion_rules.iter() {
+            write!(f, "\n{} {} {}", rule.dest_start, rule.source_start, rule.range)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Category::Seed => "seed",
+            Category::Soil => "soil",
+            Category::Fertilizer => "fertilizer",
+            Category::Water => "water",
+            Category::Light => "light",
+            Category::Temperature => "temperature",
+            Category::Humidity => "humidity",
+            Category::Location => "location"
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use aoc_common::{examples, Answer, Solution};
+    use proptest::prelude::*;
+
+    use super::{Almanac, Category, ConversionMap, Day5, Rule};
+
+    #[test]
+    fn test_examples() {
+        examples::check(&Day5, env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_seed_pairs() {
+        // part 1 does not need pairs, part 2 does
+        let example = include_str!("../fixtures/example.txt");
+        let almanac = Day5::parse(&example.replacen("seeds: 79 14 55 13", "seeds: 79 14 55", 1)).unwrap();
+        assert_eq!(Day5::part1(&almanac), Answer::from(43));
+        assert_eq!(Day5::part2(&almanac), Answer::Unsolved);
+
+        let almanac = Day5::parse(&example.replacen("seeds: 79 14 55 13", "seeds:", 1)).unwrap();
+        assert_eq!(Day5::part1(&almanac), Answer::Unsolved);
+        assert_eq!(Day5::part2(&almanac), Answer::Unsolved);
+
+        // a range without seeds has no lowest location
+        let almanac = Day5::parse(&example.replacen("seeds: 79 14 55 13", "seeds: 79 0", 1)).unwrap();
+        assert_eq!(Day5::part2(&almanac), Answer::Unsolved);
+    }
+
+    #[test]
+    fn test_odd_maps() {
+        let error = |input: &str| Day5::parse(input).unwrap_err().locate(input).to_string();
+
+        assert_eq!(error("seeds: 1 2\n\nseed-to-seed map:\n1 2 3"), "3:1: the maps go round in circles at 'seed-to-seed map:'");
+        assert_eq!(error("seeds: 1 2\n\nseed-to-soil map:\n1 2 3"), "3:1: there is no map from soil at 'seed-to-soil map:'");
+        assert_eq!(error("seeds: 1 2\n\nsoil-to-location map:\n1 2 3"), "1:1: there is no map from seed at 'seeds: 1 2'");
+        assert_eq!(error("seeds: 1 2\n\nseed-to-location map:\n1 2 3\n\nseed-to-soil map:\n1 2 3"), "6:1: there is another map from seed at 'seed-to-soil map:'");
+        assert_eq!(error("seeds: 1 2\n\nseed-to-location map:\n1 9223372036854775800 8"), "4:1: the range is too large at '1 9223372036854775800 8'");
+
+        let almanac = Day5::parse("seeds: 1 2\n\nseed-to-location map:\n1 9223372036854775800 7").unwrap();
+        assert_eq!(Day5::part2(&almanac), Answer::from(1));
+
+        // the seed ranges of part 2 have to fit into an i64
+        let almanac = Day5::parse("seeds: 9223372036854775800 8\n\nseed-to-location map:\n1 2 3").unwrap();
+        assert_eq!(Day5::part1(&almanac), Answer::from(8));
+        assert_eq!(Day5::part2(&almanac), Answer::Unsolved);
+    }
+
+    fn rule() -> impl Strategy<Value = Rule> {
+        (0..10000u64, 0..10000u64, 1..100u64).prop_map(|(dest_start, source_start, range)|
+            Rule { dest_start, source_start, range }
+        )
+    }
+
+    // a map for every step of the chain from seed to location
+    fn almanac() -> impl Strategy<Value = Almanac> {
+        let maps = prop::collection::vec(prop::collection::vec(rule(), 1..5), 7);
+
+        (prop::collection::vec(0..10000u64, 1..5), maps).prop_map(|(starts, maps)| {
+            let seeds: Vec<u64> = starts.iter().flat_map(|&start| [start, start % 100]).collect();
+            let maps: HashMap<Category, ConversionMap> = Category::ALL.windows(2).zip(maps)
+                .map(|(categories, conversion_rules)|
+                    (categories[0], ConversionMap { source_category: categories[0], dest_category: categories[1], conversion_rules })
+                )
+                .collect();
+
+            Almanac { seeds, maps }
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(almanac in almanac()) {
+            prop_assert_eq!(Day5::parse(&almanac.to_string()).unwrap(), almanac);
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use aoc_common::query::{Command, Query};
+use aoc_common::{input, Error, Result};
+
+use crate::{Almanac, Day5};
+
+const CONVERT: Command = Command { name: "convert", usage: "<seed>", help: "the way of a seed through all maps to its location" };
+const SEEDS: Command = Command { name: "seeds", usage: "", help: "the seeds of the almanac, and the ranges they make up in part 2" };
+
+impl Query for Day5 {
+    const COMMANDS: &'static [Command] = &[CONVERT, SEEDS];
+
+    fn query(almanac: &Almanac, command: &str, arguments: &[&str]) -> Result<String> {
+        match command {
+            "convert" => {
+                let [seed] = CONVERT.arguments(arguments)?;
+                let seed = input::number::<u64>(seed)?;
+
+                let mut way = format!("seed {}", seed);
+                for (map, _, dest) in almanac.conversions(seed) {
+                    way.push_str(&format!(" -> {} {}", map.dest_category, dest));
+                }
+
+                Ok(way)
+            },
+            "seeds" => {
+                let [] = SEEDS.arguments(arguments)?;
+                let seeds: Vec<String> = almanac.seeds.iter().map(|seed| seed.to_string()).collect();
+                let ranges: Vec<String> = almanac.seeds.chunks(2).map(|range| match range {
+                    [start, length] => format!("{}..{}", start, start + length),
+                    _ => format!("{}..", range[0])
+                }).collect();
+
+                Ok(format!("{}\nas ranges: {}", seeds.join(" "), ranges.join(" ")))
+            },
+            _ => Err(Error::Usage(format!("there is no command {}", command)))
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use aoc_common::number::{self, BigInt};
+use aoc_common::parse::{self, Parser};
+use aoc_common::{input, Answer, Error, Result, Solution};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Wait For It";
+
+    type Input = Vec<Race>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines = input::lines(input);
+        if lines.len() != 2 {
+            return Err(Error::parse(input, "expected a line with times and a line with distances"));
+        }
+
+        let times = numbers(lines[0], "Time")?;
+        let distances = numbers(lines[1], "Distance")?;
+        if times.len() != distances.len() {
+            return Err(Error::parse(lines[1], "expected as many distances as times"));
+        }
+
+        let mut races: Vec<Race> = Vec::new();
+        for i in 0..times.len() {
+            let time = times[i];
+            let distance = distances[i];
+
+            let race = Race { available_time: time, winning_distance: distance };
+            races.push(race);
+        }
+
+        Ok(races)
+    }
+
+    fn part1(races: &Self::Input) -> Answer {
+        let result: BigInt = races.iter().map(|race| race.winning_possibilities()).product();
+
+        result.into()
+    }
+
+    fn part2(races: &Self::Input) -> Answer {
+        // the spaces between the numbers are bad kerning, so all races are actually one race.
+        // Its numbers have all the digits, far more than a u64 holds, and there are none
+        // without any races
+        let time = races.iter().map(|race| race.available_time.to_string()).collect::<String>().parse::<BigInt>();
+        let distance = races.iter().map(|race| race.winning_distance.to_string()).collect::<String>().parse::<BigInt>();
+        let (Ok(time), Ok(distance)) = (time, distance) else {
+            return Answer::Unsolved;
+        };
+
+        winning_possibilities(&time, &distance).into()
+    }
+}
+
+// the numbers after a label like "Time:"
+fn numbers(line: &str, label: &str) -> Result<Vec<u64>> {
+    parse::all(line, |p| p.labelled(label, Parser::numbers::<u64>))
+}
+
+// the races as the two lines of the input, with the numbers lined up in columns
+pub fn print(races: &[Race]) -> String {
+    let width = races.iter()
+        .map(|race| race.available_time.to_string().len().max(race.winning_distance.to_string().len()))
+        .max().unwrap_or(0);
+    let times: Vec<String> = races.iter().map(|race| format!("{:>w$}", race.available_time, w = width)).collect();
+    let distances: Vec<String> = races.iter().map(|race| format!("{:>w$}", race.winning_distance, w = width)).collect();
+
+    format!("Time:     {}\nDistance: {}", times.join("  "), distances.join("  "))
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Race {
+    available_time: u64,
+    winning_distance: u64
+}
+
+impl Race {
+    fn winning_possibilities(&self) -> BigInt {
+        winning_possibilities(&BigInt::from(self.available_time), &BigInt::from(self.winning_distance))
+    }
+}
+
+fn winning_possibilities(time: &BigInt, distance: &BigInt) -> BigInt {
+    match winning_range(time, distance) {
+        Some((p1, p2)) => p2 - p1 + 1,
+        None => BigInt::from(0)
+    }
+}
+
+fn winning_range(time: &BigInt, distance: &BigInt) -> Option<(BigInt, BigInt)> {
+    // we need to solve 0 > p^2 - pT + D
+    // where p is the button push duration, T is the available time for the race and D is the distance to beat
+    // T^2 does not fit into an i128 for the largest times, so it is a BigInt
+
+    number::quadratic_below_zero(&BigInt::from(1), &-time, distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{examples, Answer, Solution};
+    use proptest::prelude::*;
+
+    use super::{print, Day6, Race};
+
+    #[test]
+    fn test_examples() {
+        examples::check(&Day6, env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_large_races() {
+        let races = Day6::parse("Time:\nDistance:").unwrap();
+        assert_eq!(Day6::part2(&races), Answer::Unsolved);
+
+        // every push but none and all of the time wins
+        let races = Day6::parse("Time:     18446744073709551615  7\nDistance:                    0  0").unwrap();
+        assert_eq!(Day6::part1(&races), Answer::from(18446744073709551614u128 * 6));
+        assert_eq!(Day6::part2(&races), Answer::from(184467440737095516156u128));
+
+        // far more than an i128 holds, it is still the answer
+        let races = Day6::parse("Time:     18446744073709551615  18446744073709551615\nDistance: 0  0").unwrap();
+        assert_eq!(Day6::part2(&races), Answer::Text(String::from("1844674407370955161518446744073709551614")));
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(races in prop::collection::vec((0..100000u64, 0..100000000u64), 0..6)) {
+            let races: Vec<Race> = races.into_iter().map(|(available_time, winning_distance)|
+                Race { available_time, winning_distance }
+            ).collect();
+
+            prop_assert_eq!(Day6::parse(&print(&races)).unwrap(), races);
+        }
+    }
+}
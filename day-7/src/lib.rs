@@ -0,0 +1,259 @@
+use std::fmt;
+use aoc_common::trace::{self, Level};
+use aoc_common::{input, Answer, Error, Result, Solution};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Camel Cards";
+
+    type Input = Vec<Hand>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines = input::lines(input);
+
+        lines.iter().map(|line| Hand::from(line)).collect()
+    }
+
+    fn part1(hands: &Self::Input) -> Answer {
+        total_winnings(hands, false).into()
+    }
+
+    fn part2(hands: &Self::Input) -> Answer {
+        total_winnings(hands, true).into()
+    }
+}
+
+fn total_winnings(hands: &Vec<Hand>, jokers: bool) -> u64 {
+    // the index of the hand comes last, it only tells which hand it was
+    let mut ranked: Vec<(Kind, [u8; 5], u32, usize)> = hands.iter().enumerate().map(|(i, hand)| {
+        let kind = hand.kind(jokers);
+        trace::explain(Level::Steps, || format!("{}: {:?}", hand.card_text(), kind));
+
+        (kind, hand.values(jokers), hand.bid, i)
+    }).collect();
+    ranked.sort();
+
+    let ranks: u64 = ranked.iter().enumerate().map(|p| {
+        let v = (p.0 + 1) as u64 * p.1.2 as u64;
+        trace::explain(Level::Details, || format!("  rank {}: {} ({:?}) wins {} * {} = {}", p.0 + 1, hands[p.1.3].card_text(), p.1.0, p.0 + 1, p.1.2, v));
+        v
+    }).sum();
+
+    ranks
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Hand {
+    bid: u32,
+    cards: [Card; 5]
+}
+
+impl Hand {
+    fn from(line: &str) -> Result<Self> {
+        let (cards, bid) = input::split_pair(line, " ")?;
+
+        let cards = Self::cards(cards)?;
+        let bid = input::number::<u32>(bid)?;
+
+        Ok(Hand { bid, cards })
+    }
+
+    fn cards(cards_str: &str) -> Result<[Card; 5]> {
+        let mut cards: [Card; 5] = [Card::Two; 5];
+
+        let parsed: Vec<Card> = input::chars(cards_str).map(|(_, card)|
+            Card::from(card)
+        ).collect::<Result<_>>()?;
+        if parsed.len() != 5 {
+            return Err(Error::parse(cards_str, "a hand needs exactly 5 cards"));
+        }
+        cards.copy_from_slice(&parsed);
+
+        Ok(cards)
+    }
+
+    fn kind(&self, jokers: bool) -> Kind {
+        Kind::from(self.cards, jokers)
+    }
+
+    fn card_text(&self) -> String {
+        self.cards.iter().map(|card| card.to_string()).collect()
+    }
+
+    // card values in hand order, used to break ties between hands of the same kind
+    fn values(&self, jokers: bool) -> [u8; 5] {
+        self.cards.map(|card| card.value(jokers))
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+enum Kind {
+    FiveOfAKind = 7,
+    FourOfAKind = 6,
+    FullHouse = 5,
+    ThreeOfAKind = 4,
+    TwoPair = 3,
+    OnePair = 2,
+    HighCard = 1
+}
+
+impl Kind {
+    fn from(cards: [Card; 5], jokers: bool) -> Self {
+        // index 0 is only used for jokers, see Card::value
+        let mut card_values: [u32; 14] = [0; 14];
+
+        for card in cards {
+            let card_pos = card.value(jokers) as usize;
+            card_values[card_pos] = card_values[card_pos] + 1;
+        }
+        let joker_count = card_values[0];
+        card_values[0] = 0;
+
+        card_values.sort();
+        card_values.reverse();
+        card_values[0] = card_values[0] + joker_count;
+
+        // now check all possibilities
+        if card_values[0] == 5 {
+            return Kind::FiveOfAKind;
+        } else if card_values[0] == 4 { // pos 1 MUST be 1
+            return Kind::FourOfAKind;
+        } else if card_values[0] == 3 && card_values[1] == 2 {
+            return Kind::FullHouse;
+        } else if card_values[0] == 3 && card_values[1] == 1 && card_values[2] == 1 {
+            return Kind::ThreeOfAKind;
+        } else if card_values[0] == 2 && card_values[1] == 2 { // pos 2 MUST be 1
+            return Kind::TwoPair;
+        } else if card_values[0] == 2 && card_values[1] == 1 && card_values[2] == 1 { // pos 3 MUST be 1
+            return Kind::OnePair;
+        } else {
+            Kind::HighCard
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd)]
+enum Card {
+    A = 12,
+    K = 11,
+    Q = 10,
+    J = 9,
+    T = 8,
+    Nine = 7,
+    Eight = 6,
+    Seven = 5,
+    Six = 4,
+    Five = 3,
+    Four = 2,
+    Three = 1,
+    Two = 0
+}
+
+impl Card {
+    fn from(item: &str) -> Result<Self> {
+        match item {
+            "A" => Ok(Card::A),
+            "K" => Ok(Card::K),
+            "Q" => Ok(Card::Q),
+            "J" => Ok(Card::J),
+            "T" => Ok(Card::T),
+            "9" => Ok(Card::Nine),
+            "8" => Ok(Card::Eight),
+            "7" => Ok(Card::Seven),
+            "6" => Ok(Card::Six),
+            "5" => Ok(Card::Five),
+            "4" => Ok(Card::Four),
+            "3" => Ok(Card::Three),
+            "2" => Ok(Card::Two),
+            _ => Err(Error::parse(item, "unknown card"))
+        }
+    }
+
+    // with jokers, J is the weakest card of all
+    fn value(&self, jokers: bool) -> u8 {
+        if jokers && *self == Card::J {
+            0
+        } else {
+            *self as u8 + 1
+        }
+    }
+}
+
+impl fmt::Display for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for card in self.cards {
+            write!(f, "{}", card)?;
+        }
+
+        write!(f, " {}", self.bid)
+    }
+}
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Card::A => 'A',
+            Card::K => 'K',
+            Card::Q => 'Q',
+            Card::J => 'J',
+            Card::T => 'T',
+            Card::Nine => '9',
+            Card::Eight => '8',
+            Card::Seven => '7',
+            Card::Six => '6',
+            Card::Five => '5',
+            Card::Four => '4',
+            Card::Three => '3',
+            Card::Two => '2'
+        };
+
+        write!(f, "{}", label)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::trace::{self, Level};
+    use aoc_common::{examples, Solution};
+    use proptest::prelude::*;
+
+    use super::{Card, Day7, Hand};
+
+    #[test]
+    fn test_examples() {
+        examples::check(&Day7, env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_explain() {
+        let hands = Day7::parse("32T3K 765\nKTJJT 220").unwrap();
+        let (_, lines) = trace::record(Level::Details, || Day7::part2(&hands));
+
+        assert_eq!(lines, vec![
+            "32T3K: OnePair",
+            "KTJJT: FourOfAKind",
+            "  rank 1: 32T3K (OnePair) wins 1 * 765 = 765",
+            "  rank 2: KTJJT (FourOfAKind) wins 2 * 220 = 440"
+        ]);
+    }
+
+    fn hand() -> impl Strategy<Value = Hand> {
+        let card = prop::sample::select(vec![
+            Card::A, Card::K, Card::Q, Card::J, Card::T, Card::Nine, Card::Eight,
+            Card::Seven, Card::Six, Card::Five, Card::Four, Card::Three, Card::Two
+        ]);
+
+        (prop::array::uniform5(card), 0..1000u32).prop_map(|(cards, bid)| Hand { bid, cards })
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(hands in prop::collection::vec(hand(), 1..10)) {
+            let printed: Vec<String> = hands.iter().map(|hand| hand.to_string()).collect();
+
+            prop_assert_eq!(Day7::parse(&printed.join("\n")).unwrap(), hands);
+        }
+    }
+}
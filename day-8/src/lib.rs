@@ -0,0 +1,253 @@
+use std::collections::HashMap;
+use std::fmt;
+use aoc_common::cycle::{self, Cycle};
+use aoc_common::number::{self, BigInt};
+use aoc_common::{input, Answer, Error, Result, Solution};
+use aoc_common::geometry::Turn;
+use aoc_common::parse;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Haunted Wasteland";
+
+    type Input = (Vec<Turn>, Map);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines = input::lines(input);
+        let Some(&first_line) = lines.get(0) else {
+            return Err(Error::parse(input, "expected a line of L/R instructions"));
+        };
+        let instructions: Vec<Turn> = input::chars(first_line).map(|(_, direction)|
+            Turn::from_letter(direction)
+        ).collect::<Result<_>>()?;
+
+        // the nodes follow after an empty line
+        if let Some(&separator) = lines.get(1).filter(|line| !line.is_empty()) {
+            return Err(Error::parse(separator, "expected an empty line"));
+        }
+        let nodes = lines.get(2..).unwrap_or(&[]);
+        let map = Map::from(nodes)?;
+
+        Ok((instructions, map))
+    }
+
+    fn part1((instructions, map): &Self::Input) -> Answer {
+        match map.follow(instructions, "AAA", "ZZZ") {
+            Some(steps) => steps.into(),
+            None => Answer::Unsolved
+        }
+    }
+
+    fn part2((instructions, map): &Self::Input) -> Answer {
+        let start_nodes: Vec<String> = map.paths.keys().filter(|key|
+            key.ends_with("A")
+        ).map(|node|
+            String::from(node)
+        ).collect();
+        if start_nodes.is_empty() {
+            return Answer::Unsolved;
+        }
+
+        match map.find_solution(&start_nodes, instructions) {
+            Some(steps) => steps.into(),
+            None => Answer::Unsolved
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Map {
+    paths: HashMap<String, (String, String)>
+}
+
+impl Map {
+    fn from(lines: &[&str]) -> Result<Self> {
+        let mut nodes: Vec<(&str, &str, &str)> = Vec::new();
+        // example line
+        // AAA = (BBB, CCC)
+        for line in lines {
+            let node = parse::all(line, |p| {
+                let from = p.word()?;
+                p.expect(" = ")?;
+                let (to_left, to_right) = p.bracketed("(", ")", |p| {
+                    let to_left = p.word()?;
+                    p.expect(", ")?;
+
+                    Ok((to_left, p.word()?))
+                })?;
+
+                Ok((from, to_left, to_right))
+            })?;
+
+            nodes.push(node);
+        }
+
+        let mut paths: HashMap<String, (String, String)> = HashMap::new();
+        for &(from, to_left, to_right) in nodes.iter() {
+            paths.insert(String::from(from), (String::from(to_left), String::from(to_right)));
+        }
+
+        // every step has to lead to a node of the map, so that next_path always finds one
+        for &(_, to_left, to_right) in nodes.iter() {
+            for to in [to_left, to_right] {
+                if !paths.contains_key(to) {
+                    return Err(Error::parse(to, "unknown node"));
+                }
+            }
+        }
+
+        Ok(Map { paths })
+    }
+
+    // the number of steps from start to end, None if start is not on the map or the walk runs
+    // in a circle without ever reaching end. The walk repeats itself once the node and the next
+    // instruction do, so the states up to the second round of the cycle are all there is to see
+    fn follow(&self, instructions: &Vec<Turn>, start: &str, end: &str) -> Option<usize> {
+        if !self.paths.contains_key(start) {
+            return None;
+        }
+
+        let (_, states) = cycle::find((start, 0), |&(node, i)|
+            (self.next_path(node, &instructions[i]), (i + 1) % instructions.len())
+        );
+
+        states.iter().position(|&(node, _)| node == end)
+    }
+
+    // the node must be on the map, which Map::from makes sure of for every node a step leads to
+    fn next_path(&self, current_pos: &str, direction: &Turn) -> &str {
+        let possible_paths = &self.paths[current_pos];
+
+        let next_pos = match direction {
+            Turn::Left => &possible_paths.0,
+            Turn::Right => &possible_paths.1
+        };
+
+        next_pos
+    }
+
+    // the first step at which every walk is at a node ending with Z, None if they never are
+    // at the same time. Each walk runs into a cycle of its node and next instruction. Before
+    // the last walk is in its cycle the steps are checked one by one, after that every walk is
+    // at a Z node at the steps which are congruent to one of its Z nodes in the cycle
+    fn find_solution(&self, start_nodes: &Vec<String>, path: &Vec<Turn>) -> Option<BigInt> {
+        let walks: Vec<(Cycle, Vec<(&str, usize)>)> = start_nodes.iter().map(|start_node|
+            cycle::find((start_node.as_str(), 0), |&(node, i)|
+                (self.next_path(node, &path[i]), (i + 1) % path.len())
+            )
+        ).collect();
+        let at_end = |states: &Vec<(&str, usize)>, step: usize| states[step].0.ends_with("Z");
+
+        let prefix = walks.iter().map(|(cycle, _)| cycle.prefix).max().unwrap_or(0);
+        for step in 0..prefix {
+            if walks.iter().all(|(cycle, states)| at_end(states, cycle.index(step))) {
+                return Some(BigInt::from(step));
+            }
+        }
+
+        // the steps in the cycles at which all walks so far are at the end, as remainders
+        // modulo the lcm of their periods
+        let mut congruences: Vec<(BigInt, BigInt)> = vec![(BigInt::from(0), BigInt::from(1))];
+        for (cycle, states) in walks.iter() {
+            let period = BigInt::from(cycle.period);
+            let ends: Vec<BigInt> = (cycle.prefix..cycle.prefix + cycle.period)
+                .filter(|&step| at_end(states, step))
+                .map(BigInt::from)
+                .collect();
+
+            congruences = congruences.iter().flat_map(|congruence|
+                ends.iter().filter_map(|end| number::crt([congruence.clone(), (end.clone(), period.clone())]))
+            ).collect();
+        }
+
+        // the first of the steps from the prefix on
+        let prefix = BigInt::from(prefix);
+        congruences.into_iter().map(|(remainder, modulus)| {
+            let behind = (&prefix - &remainder).max(BigInt::from(0));
+            let rounds = (behind + &modulus - 1) / &modulus;
+
+            remainder + rounds * modulus
+        }).min()
+    }
+}
+
+// the instructions, an empty line and then the map
+pub fn print(instructions: &[Turn], map: &Map) -> String {
+    let instructions: String = instructions.iter().map(|turn| turn.to_string()).collect();
+
+    format!("{}\n\n{}", instructions, map)
+}
+
+// one node per line, sorted by name
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut nodes: Vec<(&String, &(String, String))> = self.paths.iter().collect();
+        nodes.sort();
+
+        let lines: Vec<String> = nodes.iter().map(|(from, (left, right))|
+            format!("{} = ({}, {})", from, left, right)
+        ).collect();
+
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{examples, Answer, Solution};
+    use aoc_common::geometry::Turn;
+    use proptest::prelude::*;
+
+    use super::{print, Day8, Map};
+
+    #[test]
+    fn test_examples() {
+        examples::check(&Day8, env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_walks_out_of_step() {
+        // one walk is at its end at every odd step, the other at every third step from step 3 on
+        let input = "L\n\n11A = (11Z, 11Z)\n11Z = (11A, 11A)\n22A = (22B, 22B)\n22B = (22C, 22C)\n22C = (22Z, 22Z)\n22Z = (22B, 22B)";
+        assert_eq!(Day8::part2(&Day8::parse(input).unwrap()), Answer::Number(3));
+
+        // the first walk is at its end at odd steps only, the second at even ones
+        let input = "L\n\n11A = (11Z, 11Z)\n11Z = (11A, 11A)\n22A = (22B, 22B)\n22B = (22Z, 22Z)\n22Z = (22B, 22B)";
+        assert_eq!(Day8::part2(&Day8::parse(input).unwrap()), Answer::Unsolved);
+    }
+
+    // every node leads to two nodes of the map
+    fn map() -> impl Strategy<Value = Map> {
+        prop::collection::hash_set("[A-Z0-9]{3}", 1..10).prop_flat_map(|nodes| {
+            let nodes: Vec<String> = nodes.into_iter().collect();
+            let node = prop::sample::select(nodes.clone());
+
+            (Just(nodes.clone()), prop::collection::vec((node.clone(), node), nodes.len()))
+        }).prop_map(|(nodes, targets)| Map { paths: nodes.into_iter().zip(targets).collect() })
+    }
+
+    #[test]
+    fn test_odd_maps() {
+        let input = "L\n\nAAA = (BBB, BBB)\nBBB = (AAA, CCC)";
+        assert_eq!(Day8::parse(input).unwrap_err().locate(input).to_string(), "4:13: unknown node at 'CCC'");
+        let input = "L\nAAA = (AAA, AAA)";
+        assert_eq!(Day8::parse(input).unwrap_err().locate(input).to_string(), "2:1: expected an empty line at 'AAA = (AAA, AAA)'");
+
+        // ZZZ is only reached by a right turn
+        let input = Day8::parse("L\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        assert_eq!(Day8::part1(&input), Answer::Unsolved);
+        let input = Day8::parse("RL\n\nBBA = (BBB, BBB)\nBBB = (BBA, BBA)").unwrap();
+        assert_eq!(Day8::part1(&input), Answer::Unsolved);
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(instructions in prop::collection::vec(prop::sample::select(vec![Turn::Left, Turn::Right]), 1..20), map in map()) {
+            let printed = print(&instructions, &map);
+
+            prop_assert_eq!(Day8::parse(&printed).unwrap(), (instructions, map));
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use std::fmt;
+use aoc_common::{input, Answer, Error, Result, Solution};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Mirage Maintenance";
+
+    type Input = Oasis;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines = input::lines(input);
+
+        Oasis::from(&lines)
+    }
+
+    fn part1(oasis: &Self::Input) -> Answer {
+        match oasis.next_values() {
+            Some(values) => values.iter().map(|&value| value as i128).sum::<i128>().into(),
+            None => Answer::Unsolved
+        }
+    }
+
+    fn part2(oasis: &Self::Input) -> Answer {
+        match oasis.previous_values() {
+            Some(values) => values.iter().map(|&value| value as i128).sum::<i128>().into(),
+            None => Answer::Unsolved
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Oasis {
+    sensor_readings: Vec<Vec<i64>>
+}
+
+impl Oasis {
+    fn from(lines: &Vec<&str>) -> Result<Self> {
+        let mut sensor_readings: Vec<Vec<i64>> = Vec::new();
+
+        for line in lines {
+            let readings = Self::extract_reading(line)?;
+            if readings.is_empty() {
+                return Err(Error::parse(line, "expected a reading"));
+            }
+
+            sensor_readings.push(readings);
+        }
+
+        Ok(Oasis { sensor_readings })
+    }
+
+    // None if a value cannot be extrapolated, see extrapolate
+    fn next_values(&self) -> Option<Vec<i64>> {
+        self.sensor_readings.iter().map(|reading| {
+            Self::extrapolate(reading).map(|(_, next)| next)
+        }).collect()
+    }
+
+    fn previous_values(&self) -> Option<Vec<i64>> {
+        self.sensor_readings.iter().map(|reading| {
+            Self::extrapolate(reading).map(|(previous, _)| previous)
+        }).collect()
+    }
+
+    // the values before the first one and after the last one. The reading is expanded into the
+    // differences between its values, and their differences, until they are all zero or only one
+    // is left, which stays the same. None if the numbers do not fit into an i64
+    fn extrapolate(reading: &[i64]) -> Option<(i64, i64)> {
+        let mut expanded_readings: Vec<Vec<i64>> = vec![reading.to_vec()];
+        while let Some(current_reading) = expanded_readings.last() {
+            if current_reading.len() <= 1 || current_reading.iter().all(|&value| value == 0) {
+                break;
+            }
+
+            let next_reading = current_reading.windows(2)
+                .map(|window| window[1].checked_sub(window[0]))
+                .collect::<Option<Vec<i64>>>()?;
+            expanded_readings.push(next_reading);
+        }
+
+        // now start by the last expanded reading and generate one additional item at both ends
+        // of every reading above it
+        let (mut previous, mut next) = (0i64, 0i64);
+        for reading in expanded_readings.iter().rev() {
+            previous = reading.first()?.checked_sub(previous)?;
+            next = reading.last()?.checked_add(next)?;
+        }
+
+        Some((previous, next))
+    }
+
+    fn extract_reading(line: &str) -> Result<Vec<i64>> {
+        line.split_whitespace().map(|item|
+            input::number::<i64>(item)
+        ).collect()
+    }
+}
+
+impl fmt::Display for Oasis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lines: Vec<String> = self.sensor_readings.iter().map(|reading|
+            reading.iter().map(|value| value.to_string()).collect::<Vec<String>>().join(" ")
+        ).collect();
+
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{examples, Answer, Solution};
+    use proptest::prelude::*;
+
+    use super::{Day9, Oasis};
+
+    #[test]
+    fn test_examples() {
+        examples::check(&Day9, env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_short_readings() {
+        // a single value stays the same, the differences of 1 2 4 end in a single 1
+        let oasis = Day9::parse("5\n1 2 4").unwrap();
+        assert_eq!((Day9::part1(&oasis), Day9::part2(&oasis)), (Answer::Number(5 + 7), Answer::Number(5 + 1)));
+
+        let oasis = Day9::parse("-9223372036854775808 9223372036854775807").unwrap();
+        assert_eq!(Day9::part1(&oasis), Answer::Unsolved);
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(sensor_readings in prop::collection::vec(prop::collection::vec(any::<i64>(), 1..10), 1..10)) {
+            let oasis = Oasis { sensor_readings };
+
+            prop_assert_eq!(Day9::parse(&oasis.to_string()).unwrap(), oasis);
+        }
+    }
+}
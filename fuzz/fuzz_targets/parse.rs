@@ -0,0 +1,37 @@
+#![no_main]
+
+use aoc_common::Puzzle;
+use libfuzzer_sys::fuzz_target;
+
+const PUZZLES: &[&dyn Puzzle] = &[
+    &day_1::Day1,
+    &day_2::Day2,
+    &day_3::Day3,
+    &day_4::Day4,
+    &day_5::Day5,
+    &day_6::Day6,
+    &day_7::Day7,
+    &day_8::Day8,
+    &day_9::Day9,
+    &day_10::Day10,
+    &day_11::Day11,
+    &day_12::Day12,
+    &day_13::Day13,
+    &day_14::Day14,
+    &day_15::Day15,
+    &day_16::Day16,
+    &day_18::Day18,
+    &day_19::Day19,
+    &day_21::Day21,
+    &day_23::Day23,
+    &day_24::Day24,
+];
+
+// the first byte picks the day, the rest is its input. Parsing may fail, but never panic
+fuzz_target!(|data: &[u8]| {
+    let Some((&day, input)) = data.split_first() else { return };
+    let Ok(input) = std::str::from_utf8(input) else { return };
+
+    let puzzle = PUZZLES[day as usize % PUZZLES.len()];
+    let _ = puzzle.parse_input(input);
+});